@@ -0,0 +1,380 @@
+use std::{io::Write, path::PathBuf};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use redefmt_db::{
+    DbClient, StateDir,
+    bundle::Bundle,
+    crate_table::{CrateName, CrateTable},
+    gc::{IdReuse, ReferencedStatements, StatementGc},
+};
+use redefmt_decoder::{RedefmtDecoder, RedefmtDecoderCache};
+use redefmt_pretty_printer::{
+    PrettyPrinter,
+    config::{PrettyPrinterConfig, PrintStampConfig, PrintTimestampConfig, PrintTimestampPrecisionConfig},
+};
+
+use crate::*;
+
+/// Decode and pretty print frames dispatched by `redefmt`
+///
+/// Statements are looked up in the state directory populated by the `redefmt`
+/// macros, i.e. `$XDG_STATE_HOME/redefmt` unless overridden with `$REDEFMT_STATE`
+/// or scoped to the workspace target directory with `REDEFMT_STATE_STRATEGY=target`,
+/// in the target binary itself if it was built with the `embed` feature, or in a
+/// bundle exported from the state directory.
+#[derive(Debug, Parser)]
+#[command(name = "redefmt", version)]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Decode a byte stream and pretty print each frame to stdout
+    Decode(DecodeArgs),
+    /// Export the statements of the state directory into a single bundle file
+    ///
+    /// Bundles may be shipped alongside release artifacts and decoded on other
+    /// machines with `decode --bundle`.
+    Export(ExportArgs),
+    /// Prune statements no longer referenced by the given target binaries
+    ///
+    /// Only the crates embedded in the binaries are pruned, their statements
+    /// not embedded in any of them being deleted. Binaries of every build
+    /// sharing the state directory should therefore be given at once.
+    Gc(GcArgs),
+}
+
+impl Cli {
+    pub fn run(self) -> Result<(), RedefmtCliError> {
+        match self.command {
+            Command::Decode(decode_args) => decode_args.run(),
+            Command::Export(export_args) => export_args.run(),
+            Command::Gc(gc_args) => gc_args.run(),
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+struct DecodeArgs {
+    /// Expect COBS framed frames, skipping over corrupted frames rather than aborting
+    #[arg(long, global = true)]
+    cobs: bool,
+    /// Load statements from the `.redefmt` section of the given target ELF
+    /// binary rather than from the state directory
+    #[arg(long, global = true, conflicts_with = "bundle")]
+    elf: Option<PathBuf>,
+    /// Load statements from a bundle created with `redefmt export` rather than
+    /// from the state directory
+    #[arg(long, global = true)]
+    bundle: Option<PathBuf>,
+    #[command(flatten)]
+    printer: PrinterArgs,
+    #[command(subcommand)]
+    source: Source,
+}
+
+impl DecodeArgs {
+    fn run(self) -> Result<(), RedefmtCliError> {
+        let Self { cobs, elf, bundle, printer, source } = self;
+
+        let mut printer = PrettyPrinter::new(printer.config()?);
+
+        let decoder_cache = RedefmtDecoderCache::default();
+        let mut decoder = match (elf, bundle) {
+            (Some(elf_path), _) => RedefmtDecoder::from_elf(&decoder_cache, &elf_path)?,
+            (None, Some(bundle_path)) => RedefmtDecoder::from_bundle(&decoder_cache, &bundle_path)?,
+            (None, None) => RedefmtDecoder::new(&decoder_cache)?,
+        };
+
+        if cobs {
+            decoder = decoder.with_cobs_framing();
+        }
+
+        let mut reader = source.open()?;
+
+        let stdout = std::io::stdout();
+        let mut writer = stdout.lock();
+
+        decode_stream(&mut reader, &mut decoder, &mut printer, &mut writer)?;
+
+        writer.flush().map_err(Into::into)
+    }
+}
+
+#[derive(Debug, Args)]
+struct ExportArgs {
+    /// Bundle file to write
+    output: PathBuf,
+    /// Only export the given crate, may be repeated, exports all crates if omitted
+    #[arg(long = "crate", value_name = "NAME")]
+    crates: Vec<String>,
+}
+
+impl ExportArgs {
+    fn run(self) -> Result<(), RedefmtCliError> {
+        let Self { output, crates } = self;
+
+        let crate_names = crates.into_iter().map(CrateName::new).collect::<Result<Vec<_>, _>>()?;
+
+        let state_dir = StateDir::resolve()?;
+        let bundle = Bundle::export(&state_dir, &crate_names)?;
+
+        std::fs::write(&output, bundle).map_err(|err| RedefmtCliError::BundleWrite(output, err))
+    }
+}
+
+#[derive(Debug, Args)]
+struct GcArgs {
+    /// Target ELF binary built with the `embed` feature, may be repeated
+    #[arg(long, value_name = "PATH", required = true)]
+    elf: Vec<PathBuf>,
+    /// Only report statement usage, without pruning
+    #[arg(long)]
+    dry_run: bool,
+    /// Allow pruned IDs to be reassigned once a statement table runs out of fresh ones
+    ///
+    /// Builds which referenced the pruned statements will then print the wrong
+    /// statements when decoded with the state directory.
+    #[arg(long, conflicts_with = "dry_run")]
+    reuse_ids: bool,
+}
+
+impl GcArgs {
+    fn run(self) -> Result<(), RedefmtCliError> {
+        let Self { elf, dry_run, reuse_ids } = self;
+
+        let id_reuse = match reuse_ids {
+            true => IdReuse::Allow,
+            false => IdReuse::Forbid,
+        };
+
+        let mut referenced = ReferencedStatements::default();
+
+        for elf_path in elf {
+            referenced.extend(&RedefmtDecoder::load_elf(&elf_path)?)?;
+        }
+
+        let state_dir = StateDir::resolve()?;
+        let main_db = DbClient::new_main(&state_dir)?;
+
+        let stdout = std::io::stdout();
+        let mut writer = stdout.lock();
+
+        for (krate, references) in referenced.iter() {
+            if main_db.find_crate(krate)?.is_none() {
+                return Err(RedefmtCliError::UnknownCrate(krate.name.to_string()));
+            }
+
+            let crate_db = DbClient::new_crate(&state_dir, krate)?;
+
+            match &krate.version {
+                Some(version) => writeln!(writer, "{} {version}", krate.name)?,
+                None => writeln!(writer, "{}", krate.name)?,
+            }
+
+            for usage in crate_db.usage(references)? {
+                writeln!(writer, "  {usage}")?;
+            }
+
+            if !dry_run {
+                crate_db.prune(references, id_reuse)?;
+            }
+        }
+
+        writer.flush().map_err(Into::into)
+    }
+}
+
+#[derive(Debug, Args)]
+struct PrinterArgs {
+    /// Log statement format string
+    ///
+    /// May use the named arguments "stamp", "level", "crate", "file", "line",
+    /// "column", "statement", "module" and "target". Defaults to
+    /// "{stamp} [{level}] - {crate}: {statement}".
+    #[arg(long, global = true)]
+    format: Option<String>,
+    /// How frame stamps should be printed
+    #[arg(long, global = true, value_enum, default_value_t = StampArg::Counter)]
+    stamp: StampArg,
+    /// Unit of the stamps dispatched by the target, used by the timestamp stamp printers
+    #[arg(long, global = true, value_enum, default_value_t = TimestampPrecisionArg::Milliseconds)]
+    timestamp_precision: TimestampPrecisionArg,
+    /// `strftime` inspired format string used by the timestamp stamp printers
+    ///
+    /// Defaults to RFC 3339 / ISO 8601 if none is provided.
+    #[arg(long, global = true)]
+    datetime_format: Option<String>,
+    /// Print derived types with their module path and generic arguments
+    #[arg(long, global = true)]
+    full_type_names: bool,
+    /// Format lists and tuples with non-debug format traits, joining elements with the given
+    /// separator
+    ///
+    /// Ex. `{:02x}` on a byte slice prints `de ad be ef` with `--list-separator " "`.
+    #[arg(long, global = true)]
+    list_separator: Option<String>,
+    /// Truncate hexdumps of `redefmt::Bytes` values after the given number of bytes
+    #[arg(long, global = true)]
+    bytes_limit: Option<usize>,
+}
+
+impl PrinterArgs {
+    fn config(self) -> Result<PrettyPrinterConfig, RedefmtCliError> {
+        let Self {
+            format,
+            stamp,
+            timestamp_precision,
+            datetime_format,
+            full_type_names,
+            list_separator,
+            bytes_limit,
+        } = self;
+
+        let timestamp_config = || PrintTimestampConfig {
+            timestamp_precision: timestamp_precision.into(),
+            datetime_format_string: datetime_format,
+        };
+
+        let stamp_config = match stamp {
+            StampArg::Counter => PrintStampConfig::Counter,
+            StampArg::Offset => PrintStampConfig::OffsetTimestamp(timestamp_config()),
+            StampArg::Unix => PrintStampConfig::UnixTimestamp(timestamp_config()),
+        };
+
+        let mut config = match format {
+            Some(format) => PrettyPrinterConfig::new_with_format(stamp_config, &format)?,
+            None => PrettyPrinterConfig::new(stamp_config),
+        };
+
+        if full_type_names {
+            config = config.with_full_type_names();
+        }
+
+        if let Some(list_separator) = list_separator {
+            config = config.with_list_separator(list_separator);
+        }
+
+        if let Some(bytes_limit) = bytes_limit {
+            config = config.with_bytes_limit(bytes_limit);
+        }
+
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum StampArg {
+    /// Print the raw stamp value
+    Counter,
+    /// Print stamps as an offset from the time the first frame was received
+    Offset,
+    /// Print stamps as an offset from the Unix epoch
+    Unix,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum TimestampPrecisionArg {
+    Microseconds,
+    Milliseconds,
+}
+
+impl From<TimestampPrecisionArg> for PrintTimestampPrecisionConfig {
+    fn from(precision: TimestampPrecisionArg) -> Self {
+        match precision {
+            TimestampPrecisionArg::Microseconds => PrintTimestampPrecisionConfig::Microseconds,
+            TimestampPrecisionArg::Milliseconds => PrintTimestampPrecisionConfig::Milliseconds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn verify_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn printer_args_after_source() {
+        let cli = Cli::try_parse_from(["redefmt", "decode", "stdin", "--stamp", "unix"]).unwrap();
+
+        let Command::Decode(decode_args) = cli.command else {
+            panic!("expected decode command");
+        };
+
+        assert!(matches!(decode_args.source, Source::Stdin));
+        assert!(matches!(decode_args.printer.stamp, StampArg::Unix));
+    }
+
+    #[test]
+    fn elf_after_source() {
+        let cli = Cli::try_parse_from(["redefmt", "decode", "stdin", "--elf", "firmware.elf"]).unwrap();
+
+        let Command::Decode(decode_args) = cli.command else {
+            panic!("expected decode command");
+        };
+
+        assert_eq!(Some(PathBuf::from("firmware.elf")), decode_args.elf);
+    }
+
+    #[test]
+    fn elf_bundle_conflict() {
+        let result = Cli::try_parse_from(["redefmt", "decode", "--elf", "a", "--bundle", "b", "stdin"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn export_crates() {
+        let cli = Cli::try_parse_from(["redefmt", "export", "out.bundle", "--crate", "x", "--crate", "y"]).unwrap();
+
+        let Command::Export(export_args) = cli.command else {
+            panic!("expected export command");
+        };
+
+        assert_eq!(PathBuf::from("out.bundle"), export_args.output);
+        assert_eq!(vec!["x", "y"], export_args.crates);
+    }
+
+    #[test]
+    fn gc_requires_elf() {
+        let result = Cli::try_parse_from(["redefmt", "gc", "--dry-run"]);
+        assert!(result.is_err());
+
+        let cli = Cli::try_parse_from(["redefmt", "gc", "--elf", "a", "--elf", "b"]).unwrap();
+
+        let Command::Gc(gc_args) = cli.command else {
+            panic!("expected gc command");
+        };
+
+        assert_eq!(vec![PathBuf::from("a"), PathBuf::from("b")], gc_args.elf);
+        assert!(!gc_args.dry_run);
+        assert!(!gc_args.reuse_ids);
+
+        let result = Cli::try_parse_from(["redefmt", "gc", "--elf", "a", "--dry-run", "--reuse-ids"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn invalid_format_error() {
+        let printer_args = PrinterArgs {
+            format: Some("{unknown}".to_string()),
+            stamp: StampArg::Counter,
+            timestamp_precision: TimestampPrecisionArg::Milliseconds,
+            datetime_format: None,
+            full_type_names: false,
+            list_separator: None,
+            bytes_limit: None,
+        };
+
+        let result = printer_args.config();
+
+        assert!(matches!(result, Err(RedefmtCliError::PrinterConfig(_))));
+    }
+}
@@ -0,0 +1,52 @@
+use std::io::{ErrorKind, Read, Write};
+
+use bytes::BytesMut;
+use redefmt_decoder::{RedefmtDecoder, RedefmtDecoderError};
+use redefmt_pretty_printer::PrettyPrinter;
+
+use crate::*;
+
+const READ_BUFFER_SIZE: usize = 4096;
+
+/// Decode and print frames until the reader reaches EOF
+pub fn decode_stream(
+    reader: &mut dyn Read,
+    decoder: &mut RedefmtDecoder,
+    printer: &mut PrettyPrinter,
+    writer: &mut dyn Write,
+) -> Result<(), RedefmtCliError> {
+    let mut read_buffer = [0; READ_BUFFER_SIZE];
+    let mut src = BytesMut::with_capacity(READ_BUFFER_SIZE);
+
+    loop {
+        let read_count = match reader.read(&mut read_buffer) {
+            Ok(0) => return Ok(()),
+            Ok(read_count) => read_count,
+            Err(err) if matches!(err.kind(), ErrorKind::Interrupted | ErrorKind::TimedOut) => continue,
+            Err(err) => return Err(RedefmtCliError::Read(err)),
+        };
+
+        src.extend_from_slice(&read_buffer[..read_count]);
+
+        loop {
+            let frame = match decoder.decode(&mut src) {
+                Ok(Some(frame)) => frame,
+                Ok(None) => break,
+                Err(error @ RedefmtDecoderError::CrcMismatch(_, _)) => {
+                    eprintln!("warning: dropped corrupted frame, {error}");
+                    continue;
+                }
+                Err(error) => return Err(error.into()),
+            };
+
+            if frame.dropped_bytes > 0 {
+                eprintln!("warning: dropped {} corrupted bytes", frame.dropped_bytes);
+            }
+
+            let pretty_string = printer.format(frame)?;
+            writer.write_all(pretty_string.as_bytes())?;
+        }
+
+        writer.flush()?;
+    }
+}
@@ -0,0 +1,52 @@
+use std::{error::Error, io::Error as IoError, path::PathBuf};
+
+use redefmt_args::deferred::DeferredFormatError;
+use redefmt_db::{DbClientError, StateDirError, bundle::BundleError, crate_table::CrateNameError};
+use redefmt_decoder::RedefmtDecoderError;
+use redefmt_pretty_printer::config::PrettyPrinterConfigError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RedefmtCliError {
+    #[error("failed to open file '{0}'")]
+    File(PathBuf, #[source] IoError),
+    #[error("failed to open serial device '{0}'")]
+    Serial(String, #[source] serialport::Error),
+    #[error("failed to connect to TCP socket '{0}'")]
+    Tcp(String, #[source] IoError),
+    #[error("failed to read from source")]
+    Read(#[source] IoError),
+    #[error("failed to write to stdout")]
+    Write(#[from] IoError),
+    #[error("invalid pretty printer configuration")]
+    PrinterConfig(#[from] PrettyPrinterConfigError),
+    #[error("failed to decode frame")]
+    Decoder(#[from] RedefmtDecoderError),
+    #[error("failed to format decoded frame")]
+    Format(#[from] DeferredFormatError),
+    #[error("state directory resolution error")]
+    StateDir(#[from] StateDirError),
+    #[error("invalid crate name")]
+    CrateName(#[from] CrateNameError),
+    #[error("failed to export statement bundle")]
+    Bundle(#[from] BundleError),
+    #[error("failed to write bundle to '{0}'")]
+    BundleWrite(PathBuf, #[source] IoError),
+    #[error("database failure")]
+    Db(#[from] DbClientError),
+    #[error("crate '{0}' is not registered in the state directory")]
+    UnknownCrate(String),
+}
+
+impl RedefmtCliError {
+    /// Print error along with its chain of sources to stderr
+    pub fn report(&self) {
+        eprintln!("error: {self}");
+
+        let mut source = self.source();
+
+        while let Some(error) = source {
+            eprintln!("  caused by: {error}");
+            source = error.source();
+        }
+    }
+}
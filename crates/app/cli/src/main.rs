@@ -0,0 +1,36 @@
+//! # `redefmt-cli`
+//!
+//! Host side `redefmt` binary which decodes and pretty prints frames
+//! dispatched by a target, read from either a file, stdin, a serial device
+//! or a TCP socket.
+
+// TEMP:
+#![allow(missing_docs)]
+
+use std::process::ExitCode;
+
+use clap::Parser;
+
+mod error;
+pub(crate) use error::RedefmtCliError;
+
+mod args;
+pub(crate) use args::*;
+
+mod source;
+pub(crate) use source::Source;
+
+mod decode;
+pub(crate) use decode::decode_stream;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            error.report();
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::{fs::File, io::Read, net::TcpStream, path::PathBuf, time::Duration};
+
+use clap::Subcommand;
+
+use crate::*;
+
+const DEFAULT_BAUD_RATE: u32 = 115_200;
+
+// Reads time out regularly in order to not block indefinitely on idle
+// devices, timeouts are then simply retried by the decode loop.
+const SERIAL_READ_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Byte stream source
+#[derive(Debug, Subcommand)]
+pub enum Source {
+    /// Read from a file, stops once the end of the file is reached
+    File { path: PathBuf },
+    /// Read from stdin
+    Stdin,
+    /// Read from a serial device, ex. `/dev/ttyACM0`
+    Serial {
+        path: String,
+        #[arg(long, default_value_t = DEFAULT_BAUD_RATE)]
+        baud_rate: u32,
+    },
+    /// Read from a TCP socket, ex. `127.0.0.1:9000`
+    Tcp { address: String },
+}
+
+impl Source {
+    pub fn open(self) -> Result<Box<dyn Read>, RedefmtCliError> {
+        let reader: Box<dyn Read> = match self {
+            Source::File { path } => {
+                let file = File::open(&path).map_err(|err| RedefmtCliError::File(path, err))?;
+                Box::new(file)
+            }
+            Source::Stdin => Box::new(std::io::stdin()),
+            Source::Serial { path, baud_rate } => {
+                let serial_port = serialport::new(&path, baud_rate)
+                    .timeout(SERIAL_READ_TIMEOUT)
+                    .open()
+                    .map_err(|err| RedefmtCliError::Serial(path, err))?;
+
+                Box::new(serial_port)
+            }
+            Source::Tcp { address } => {
+                let tcp_stream = TcpStream::connect(&address).map_err(|err| RedefmtCliError::Tcp(address, err))?;
+                Box::new(tcp_stream)
+            }
+        };
+
+        Ok(reader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_not_found_error() {
+        let temp_dir = std::env::temp_dir().join("redefmt-cli-nonexistent");
+
+        let source = Source::File { path: temp_dir.join("frames.bin") };
+
+        let result = source.open();
+
+        assert!(matches!(result, Err(RedefmtCliError::File(_, _))));
+    }
+}
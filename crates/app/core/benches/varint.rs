@@ -0,0 +1,55 @@
+//! Encoded size and encoding time of typical arguments, with and without varints
+//!
+//! Run with `cargo bench -p redefmt-core --bench varint`.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use redefmt_core::{SimpleTestDispatcher, write::WriteValue};
+
+const ITERATIONS: u32 = 100_000;
+
+fn main() {
+    println!(
+        "{:<24} {:>12} {:>12} {:>12} {:>12}",
+        "value", "fixed", "varint", "fixed time", "varint time"
+    );
+
+    bench("u64 counter", &3u64);
+    bench("u64 hour in milliseconds", &3_600_000u64);
+    bench("usize index", &42usize);
+    bench("i32 temperature", &-12i32);
+    bench("u16 port", &8080u16);
+    bench("&str", &"sensor ready");
+    bench("[u16; 8] samples", &[512u16, 498, 530, 1023, 0, 12, 77, 256]);
+    bench("(u32, i64, usize)", &(7u32, -1i64, 1usize));
+    bench("u64::MAX", &u64::MAX);
+}
+
+fn bench(name: &str, value: &dyn WriteValue) {
+    let (fixed_length, fixed_time) = measure(value, false);
+    let (varint_length, varint_time) = measure(value, true);
+
+    println!(
+        "{name:<24} {:>10} B {:>10} B {:>12?} {:>12?}",
+        fixed_length, varint_length, fixed_time, varint_time
+    );
+}
+
+fn measure(value: &dyn WriteValue, varint: bool) -> (usize, Duration) {
+    let mut dispatcher = SimpleTestDispatcher { varint, ..Default::default() };
+
+    value.write_value(&mut dispatcher);
+    let length = dispatcher.bytes.len();
+
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        dispatcher.bytes.clear();
+        black_box(value).write_value(&mut dispatcher);
+    }
+
+    (length, start.elapsed() / ITERATIONS)
+}
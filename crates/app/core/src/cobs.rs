@@ -0,0 +1,212 @@
+//! Consistent Overhead Byte Stuffing (COBS) framing
+//!
+//! Opt-in framing layer which guarantees that [`FRAME_DELIMITER`] only ever
+//! appears at the end of each frame. Decoders are thereby able to skip to the
+//! next frame boundary whenever bytes are lost or corrupted, at the cost of
+//! one byte per 254 bytes of frame content plus the delimiter.
+
+use crate::*;
+
+/// Terminates each encoded frame, never present within the frame itself
+pub const FRAME_DELIMITER: u8 = 0x00;
+
+// Max non-delimiter bytes in a block, the code byte preceding each block
+// being one more than its length.
+const MAX_BLOCK_LENGTH: usize = 254;
+const MAX_BLOCK_CODE: u8 = MAX_BLOCK_LENGTH as u8 + 1;
+
+#[derive(Debug, PartialEq)]
+pub enum CobsDecodeError {
+    /// Frame delimiter found within the encoded frame
+    UnexpectedDelimiter,
+    /// Block code pointing past the end of the encoded frame
+    Truncated,
+}
+
+/// Dispatcher COBS encoding each frame before passing it on to its inner dispatcher
+///
+/// Encodes in a streaming fashion, buffering at most 254 bytes at a time.
+pub struct CobsDispatcher<D> {
+    inner: D,
+    block: [u8; MAX_BLOCK_LENGTH],
+    block_length: usize,
+}
+
+impl<D: Dispatcher> CobsDispatcher<D> {
+    pub const fn new(inner: D) -> Self {
+        Self { inner, block: [0; MAX_BLOCK_LENGTH], block_length: 0 }
+    }
+
+    /// Bytes of an unterminated frame are discarded
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+
+    fn write_block(&mut self) {
+        let code = self.block_length as u8 + 1;
+
+        self.inner.write(&[code]);
+        self.inner.write(&self.block[..self.block_length]);
+
+        self.block_length = 0;
+    }
+}
+
+impl<D: Dispatcher> Dispatcher for CobsDispatcher<D> {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            if *byte == FRAME_DELIMITER {
+                self.write_block();
+                continue;
+            }
+
+            self.block[self.block_length] = *byte;
+            self.block_length += 1;
+
+            if self.block_length == MAX_BLOCK_LENGTH {
+                self.write_block();
+            }
+        }
+    }
+
+    fn end_frame(&mut self) {
+        self.write_block();
+        self.inner.write(&[FRAME_DELIMITER]);
+        self.inner.end_frame();
+    }
+}
+
+/// Decode an encoded frame in place, returning the length of the decoded frame
+///
+/// Expects the trailing frame delimiter to have been stripped.
+pub fn decode_in_place(buffer: &mut [u8]) -> Result<usize, CobsDecodeError> {
+    let mut read_index = 0;
+    let mut write_index = 0;
+
+    while read_index < buffer.len() {
+        let code = buffer[read_index];
+
+        if code == FRAME_DELIMITER {
+            return Err(CobsDecodeError::UnexpectedDelimiter);
+        }
+
+        let block_start = read_index + 1;
+        let block_end = read_index + code as usize;
+
+        if block_end > buffer.len() {
+            return Err(CobsDecodeError::Truncated);
+        }
+
+        if buffer[block_start..block_end].contains(&FRAME_DELIMITER) {
+            return Err(CobsDecodeError::UnexpectedDelimiter);
+        }
+
+        buffer.copy_within(block_start..block_end, write_index);
+        write_index += block_end - block_start;
+        read_index = block_end;
+
+        // Delimiter implied after each block not of max length, excluding the last.
+        if code != MAX_BLOCK_CODE && read_index < buffer.len() {
+            buffer[write_index] = FRAME_DELIMITER;
+            write_index += 1;
+        }
+    }
+
+    Ok(write_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_round_trip(&[], &[0x01, 0x00]);
+    }
+
+    #[test]
+    fn delimiters() {
+        assert_round_trip(&[0x00], &[0x01, 0x01, 0x00]);
+        assert_round_trip(&[0x00, 0x00], &[0x01, 0x01, 0x01, 0x00]);
+        assert_round_trip(&[0x11, 0x22, 0x00, 0x33], &[0x03, 0x11, 0x22, 0x02, 0x33, 0x00]);
+        assert_round_trip(&[0x11, 0x00, 0x00, 0x00], &[0x02, 0x11, 0x01, 0x01, 0x01, 0x00]);
+    }
+
+    #[test]
+    fn max_block() {
+        let bytes = (1..=254).collect::<Vec<u8>>();
+
+        let mut expected = alloc::vec![0xFF];
+        expected.extend_from_slice(&bytes);
+        expected.extend_from_slice(&[0x01, 0x00]);
+
+        assert_round_trip(&bytes, &expected);
+    }
+
+    #[test]
+    fn max_block_overflow() {
+        let bytes = (1..=255).collect::<Vec<u8>>();
+
+        let mut expected = alloc::vec![0xFF];
+        expected.extend_from_slice(&bytes[..254]);
+        expected.extend_from_slice(&[0x02, 0xFF, 0x00]);
+
+        assert_round_trip(&bytes, &expected);
+    }
+
+    #[test]
+    fn max_block_followed_by_delimiter() {
+        let mut bytes = (1..=254).collect::<Vec<u8>>();
+        bytes.push(0x00);
+
+        let mut expected = alloc::vec![0xFF];
+        expected.extend_from_slice(&bytes[..254]);
+        expected.extend_from_slice(&[0x01, 0x01, 0x00]);
+
+        assert_round_trip(&bytes, &expected);
+    }
+
+    #[test]
+    fn split_writes() {
+        let mut dispatcher = CobsDispatcher::new(SimpleTestDispatcher::default());
+
+        dispatcher.write(&[0x11]);
+        dispatcher.write(&[]);
+        dispatcher.write(&[0x00, 0x22]);
+        dispatcher.end_frame();
+
+        assert_eq!(&[0x02, 0x11, 0x02, 0x22, 0x00], dispatcher.into_inner().bytes.as_ref());
+    }
+
+    #[test]
+    fn unexpected_delimiter_error() {
+        let error = decode_in_place(&mut [0x03, 0x11, 0x00]).unwrap_err();
+        assert_eq!(CobsDecodeError::UnexpectedDelimiter, error);
+
+        let error = decode_in_place(&mut [0x00, 0x11]).unwrap_err();
+        assert_eq!(CobsDecodeError::UnexpectedDelimiter, error);
+    }
+
+    #[test]
+    fn truncated_error() {
+        let error = decode_in_place(&mut [0x03, 0x11]).unwrap_err();
+        assert_eq!(CobsDecodeError::Truncated, error);
+    }
+
+    fn assert_round_trip(bytes: &[u8], expected_encoded: &[u8]) {
+        let mut dispatcher = CobsDispatcher::new(SimpleTestDispatcher::default());
+        dispatcher.write(bytes);
+        dispatcher.end_frame();
+
+        let mut encoded = dispatcher.into_inner().bytes;
+        assert_eq!(expected_encoded, encoded.as_ref());
+
+        let (delimiter, frame) = encoded.split_last_mut().unwrap();
+        assert_eq!(FRAME_DELIMITER, *delimiter);
+
+        let decoded_length = decode_in_place(frame).unwrap();
+        assert_eq!(bytes, &frame[..decoded_length]);
+    }
+}
@@ -1,5 +1,21 @@
 pub trait Dispatcher {
     fn write(&mut self, bytes: &[u8]);
+
+    /// Called once all bytes of a frame have been written
+    ///
+    /// No-op by default, used by framing dispatchers such as
+    /// [`CobsDispatcher`](crate::cobs::CobsDispatcher) to terminate frames.
+    fn end_frame(&mut self) {}
+
+    /// Whether integers and lengths are written as [`varint`](crate::varint)s
+    ///
+    /// Only overridden by the global logger for frames advertising
+    /// [`HeaderExtension::VARINT`](crate::frame::HeaderExtension::VARINT),
+    /// dispatcher implementations should keep the default.
+    #[doc(hidden)]
+    fn varint(&self) -> bool {
+        false
+    }
 }
 
 #[cfg(feature = "testing")]
@@ -26,12 +42,18 @@ mod testing {
         #[derive(Default)]
         pub struct SimpleTestDispatcher {
             pub bytes: BytesMut,
+            /// Write integers and lengths as varints
+            pub varint: bool,
         }
 
         impl Dispatcher for SimpleTestDispatcher {
             fn write(&mut self, bytes: &[u8]) {
                 self.bytes.put_slice(bytes);
             }
+
+            fn varint(&self) -> bool {
+                self.varint
+            }
         }
     }
 
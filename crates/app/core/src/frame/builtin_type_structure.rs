@@ -0,0 +1,52 @@
+/// Type structures of core library types
+///
+/// Encoded in place of a crate and type structure ID pair, given that the
+/// core library isn't able to register its type structures in any crate
+/// database. Variant order must match that of the decoder's registrations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BuiltinTypeStructure {
+    Option = 0,
+    Result = 1,
+    Ordering = 2,
+    Bound = 3,
+    Poll = 4,
+    ControlFlow = 5,
+}
+
+impl BuiltinTypeStructure {
+    pub const fn from_repr(repr: u8) -> Option<Self> {
+        let builtin = match repr {
+            0 => Self::Option,
+            1 => Self::Result,
+            2 => Self::Ordering,
+            3 => Self::Bound,
+            4 => Self::Poll,
+            5 => Self::ControlFlow,
+            _ => return None,
+        };
+
+        Some(builtin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repr_bijectivity() {
+        assert_repr_bijectivity(BuiltinTypeStructure::Option);
+        assert_repr_bijectivity(BuiltinTypeStructure::Result);
+        assert_repr_bijectivity(BuiltinTypeStructure::Ordering);
+        assert_repr_bijectivity(BuiltinTypeStructure::Bound);
+        assert_repr_bijectivity(BuiltinTypeStructure::Poll);
+        assert_repr_bijectivity(BuiltinTypeStructure::ControlFlow);
+
+        fn assert_repr_bijectivity(builtin: BuiltinTypeStructure) {
+            let repr = builtin as u8;
+            let from_repr = BuiltinTypeStructure::from_repr(repr).unwrap();
+            assert_eq!(builtin, from_repr);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+/// CRC-16/CCITT-FALSE checksum
+///
+/// Bitwise rather than table driven in order to keep the target binary size
+/// down, frames being short enough for this not to matter much.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crc16(u16);
+
+const POLYNOMIAL: u16 = 0x1021;
+const INITIAL: u16 = 0xFFFF;
+
+impl Crc16 {
+    pub const fn new() -> Self {
+        Self(INITIAL)
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= (*byte as u16) << 8;
+
+            for _ in 0..8 {
+                self.0 = match self.0 & 0x8000 != 0 {
+                    true => (self.0 << 1) ^ POLYNOMIAL,
+                    false => self.0 << 1,
+                };
+            }
+        }
+    }
+
+    pub fn finish(self) -> u16 {
+        self.0
+    }
+}
+
+impl Default for Crc16 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_value() {
+        let mut crc = Crc16::new();
+        crc.update(b"123456789");
+        assert_eq!(0x29B1, crc.finish());
+    }
+
+    #[test]
+    fn incremental() {
+        let mut crc = Crc16::new();
+        crc.update(b"1234");
+        crc.update(b"");
+        crc.update(b"56789");
+        assert_eq!(0x29B1, crc.finish());
+    }
+}
@@ -7,8 +7,13 @@ pub struct Header(u8);
 
 bitflags! {
     impl Header: u8 {
+        // `PLUS_32_WIDTH` without `PLUS_16_WIDTH` marks an extended header, see `HeaderExtension`
         const PLUS_16_WIDTH = 0b00000001;
         const PLUS_32_WIDTH = 0b00000010;
+        // Frame content followed by a big-endian CRC-16 trailer, see `Crc16`
+        const CRC = 0b00000100;
+        // Header followed by a big-endian wrapping u16 frame sequence number
+        const SEQUENCE = 0b00001000;
         const LEVEL_TRACE = 0b01000000;
         const LEVEL_DEBUG = 0b00100000;
         const LEVEL_INFO = 0b01100000;
@@ -53,10 +58,47 @@ impl Header {
         header
     }
 
+    /// Header followed by a [`HeaderExtension`]
+    ///
+    /// Signalled by the 48-bit pointer width combination, which is otherwise
+    /// never written given that word sizes are usually in powers of 2.
+    pub fn is_extended(&self) -> bool {
+        self.contains(Header::PLUS_32_WIDTH) && !self.contains(Header::PLUS_16_WIDTH)
+    }
+
+    /// Move the pointer width, sequence and stamp flags into an extension
+    /// carrying the given flags
+    pub fn split_extension(self, flags: HeaderExtension) -> (Header, HeaderExtension) {
+        let mut extension = flags | HeaderExtension::MARKER;
+        extension.set(HeaderExtension::PLUS_16_WIDTH, self.contains(Header::PLUS_16_WIDTH));
+        extension.set(HeaderExtension::PLUS_32_WIDTH, self.contains(Header::PLUS_32_WIDTH));
+        extension.set(HeaderExtension::SEQUENCE, self.contains(Header::SEQUENCE));
+        extension.set(HeaderExtension::STAMP, self.contains(Header::STAMP));
+
+        let mut header = self - Header::PLUS_16_WIDTH - Header::SEQUENCE - Header::STAMP;
+        header |= Header::PLUS_32_WIDTH;
+
+        (header, extension)
+    }
+
+    /// Restore the flags moved by [`Self::split_extension`]
+    pub fn merge_extension(self, extension: HeaderExtension) -> Header {
+        let mut header = self;
+        header.set(
+            Header::PLUS_16_WIDTH,
+            extension.contains(HeaderExtension::PLUS_16_WIDTH),
+        );
+        header.set(
+            Header::PLUS_32_WIDTH,
+            extension.contains(HeaderExtension::PLUS_32_WIDTH),
+        );
+        header.set(Header::SEQUENCE, extension.contains(HeaderExtension::SEQUENCE));
+        header.set(Header::STAMP, extension.contains(HeaderExtension::STAMP));
+
+        header
+    }
+
     pub fn pointer_width(&self) -> PointerWidth {
-        // NOTE: 48-bit pointer width is technically representable, but we
-        // ignore this as a reality for now since word sizes are usually in
-        // powers of 2
         if self.contains(Header::PLUS_32_WIDTH) {
             PointerWidth::U64
         } else if self.contains(Header::PLUS_16_WIDTH) {
@@ -102,4 +144,25 @@ mod tests {
 
         assert!(header.level().is_none_or(|header| header == Level::Info));
     }
+
+    #[test]
+    fn extension() {
+        let header = Header::new(true, Some(Level::Warn)) | Header::CRC | Header::SEQUENCE;
+
+        let (extended_header, extension) = header.split_extension(HeaderExtension::VARINT);
+
+        assert!(!header.is_extended());
+        assert!(extended_header.is_extended());
+        assert!(!extended_header.intersects(Header::STAMP | Header::SEQUENCE));
+        assert!(extension.contains(HeaderExtension::MARKER | HeaderExtension::VARINT));
+
+        // Read as the first byte of the print crate ID by decoders unaware of extensions
+        assert!(u16::from_be_bytes([extension.bits(), 0]) >= 0x8000);
+
+        let merged_header = extended_header.merge_extension(extension);
+
+        assert_eq!(header, merged_header);
+        assert_eq!(Some(Level::Warn), merged_header.level());
+        assert_eq!(PointerWidth::of_target(), merged_header.pointer_width());
+    }
 }
@@ -0,0 +1,24 @@
+use bitflags::bitflags;
+
+/// Byte following headers with [`Header::is_extended`](crate::frame::Header::is_extended)
+///
+/// All header bits being taken, flags introduced later on live here instead.
+/// The pointer width, sequence and stamp flags are moved here from the header
+/// so that the extension byte directly follows it. Decoders unaware of the
+/// extension then read it as the first byte of the print crate ID, which is
+/// guaranteed to be unknown to them given that [`Self::MARKER`] is always set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeaderExtension(u8);
+
+bitflags! {
+    impl HeaderExtension: u8 {
+        const PLUS_16_WIDTH = 0b00000001;
+        const PLUS_32_WIDTH = 0b00000010;
+        // Integers, lengths and stamps encoded as LEB128 varints, see `varint`
+        const VARINT = 0b00000100;
+        const SEQUENCE = 0b00001000;
+        // ... remaining reserved
+        const STAMP = 0b01000000;
+        const MARKER = 0b10000000;
+    }
+}
@@ -1,6 +1,7 @@
 use core::fmt::Display;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Ordered by severity, `Trace` being the least severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Level {
     Trace,
     Debug,
@@ -22,3 +23,68 @@ impl Display for Level {
         f.write_str(str)
     }
 }
+
+/// Most verbose [`Level`] to let through, `Off` disabling all levels
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LevelFilter {
+    Off = 0,
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5,
+}
+
+impl LevelFilter {
+    pub const fn from_repr(repr: u8) -> Option<Self> {
+        let level_filter = match repr {
+            0 => Self::Off,
+            1 => Self::Error,
+            2 => Self::Warn,
+            3 => Self::Info,
+            4 => Self::Debug,
+            5 => Self::Trace,
+            _ => return None,
+        };
+
+        Some(level_filter)
+    }
+
+    pub const fn enables(self, level: Level) -> bool {
+        let level_filter = match level {
+            Level::Trace => Self::Trace,
+            Level::Debug => Self::Debug,
+            Level::Info => Self::Info,
+            Level::Warn => Self::Warn,
+            Level::Error => Self::Error,
+        };
+
+        level_filter as u8 <= self as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_filter_bijectivity() {
+        for repr in 0..=u8::MAX {
+            if let Some(level_filter) = LevelFilter::from_repr(repr) {
+                assert_eq!(repr, level_filter as u8);
+            }
+        }
+    }
+
+    #[test]
+    fn level_filter_enables() {
+        assert!(!LevelFilter::Off.enables(Level::Error));
+
+        assert!(LevelFilter::Info.enables(Level::Error));
+        assert!(LevelFilter::Info.enables(Level::Info));
+        assert!(!LevelFilter::Info.enables(Level::Debug));
+
+        assert!(LevelFilter::Trace.enables(Level::Trace));
+    }
+}
@@ -1,6 +1,15 @@
+mod builtin_type_structure;
+pub use builtin_type_structure::BuiltinTypeStructure;
+
+mod crc;
+pub use crc::Crc16;
+
 mod header;
 pub use header::Header;
 
+mod header_extension;
+pub use header_extension::HeaderExtension;
+
 mod pointer_width;
 pub use pointer_width::PointerWidth;
 
@@ -8,7 +17,7 @@ mod stamp;
 pub use stamp::Stamp;
 
 mod level;
-pub use level::Level;
+pub use level::{Level, LevelFilter};
 
 mod type_hint;
 pub use type_hint::TypeHint;
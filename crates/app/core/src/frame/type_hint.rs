@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum TypeHint {
     // ** Primitives ** 0XX
@@ -19,6 +20,19 @@ pub enum TypeHint {
     // precision types, if introduced later on.
     F32 = 33,
     F64 = 34,
+    // target address, pointer width sized
+    Pointer = 40,
+
+    // ** Core library types ** 05X
+    // seconds u64 + nanoseconds u32
+    Duration = 50,
+    // octets
+    Ipv4Addr = 51,
+    Ipv6Addr = 52,
+    // octets + port
+    SocketAddrV4 = 53,
+    // octets + port + flow info u32 + scope ID u32
+    SocketAddrV6 = 54,
 
     // ** Collections ** 1XX
 
@@ -38,9 +52,14 @@ pub enum TypeHint {
     // length + type hint for each element
     DynList = 104,
 
+    // length hint + raw bytes
+    Bytes = 105,
+
     // * Meta * 2XX
     WriteStatements = 201,
     TypeStructure = 202,
+    // builtin type structure ID in place of crate and type structure ID pair
+    BuiltinTypeStructure = 203,
 }
 
 impl TypeHint {
@@ -61,13 +80,21 @@ impl TypeHint {
             25 => Self::I128,
             33 => Self::F32,
             34 => Self::F64,
+            40 => Self::Pointer,
+            50 => Self::Duration,
+            51 => Self::Ipv4Addr,
+            52 => Self::Ipv6Addr,
+            53 => Self::SocketAddrV4,
+            54 => Self::SocketAddrV6,
             100 => Self::Tuple,
             101 => Self::Char,
             102 => Self::StringSlice,
             103 => Self::List,
             104 => Self::DynList,
+            105 => Self::Bytes,
             201 => Self::WriteStatements,
             202 => Self::TypeStructure,
+            203 => Self::BuiltinTypeStructure,
             _ => return None,
         };
 
@@ -96,13 +123,21 @@ mod tests {
         assert_repr_bijectivity(TypeHint::I128);
         assert_repr_bijectivity(TypeHint::F32);
         assert_repr_bijectivity(TypeHint::F64);
+        assert_repr_bijectivity(TypeHint::Pointer);
+        assert_repr_bijectivity(TypeHint::Duration);
+        assert_repr_bijectivity(TypeHint::Ipv4Addr);
+        assert_repr_bijectivity(TypeHint::Ipv6Addr);
+        assert_repr_bijectivity(TypeHint::SocketAddrV4);
+        assert_repr_bijectivity(TypeHint::SocketAddrV6);
         assert_repr_bijectivity(TypeHint::Tuple);
         assert_repr_bijectivity(TypeHint::Char);
         assert_repr_bijectivity(TypeHint::StringSlice);
         assert_repr_bijectivity(TypeHint::List);
         assert_repr_bijectivity(TypeHint::DynList);
+        assert_repr_bijectivity(TypeHint::Bytes);
         assert_repr_bijectivity(TypeHint::WriteStatements);
         assert_repr_bijectivity(TypeHint::TypeStructure);
+        assert_repr_bijectivity(TypeHint::BuiltinTypeStructure);
 
         fn assert_repr_bijectivity(type_hint: TypeHint) {
             let repr = type_hint as u8;
@@ -74,7 +74,7 @@ macro_rules! short_id_newtype {
         }
 
         impl $id {
-            pub fn new(inner: u16) -> Self {
+            pub const fn new(inner: u16) -> Self {
                 Self(ShortId(inner))
             }
         }
@@ -16,6 +16,10 @@ pub use format::{Format, Formatter};
 mod dispatcher;
 pub use dispatcher::*;
 
+pub mod cobs;
+
+pub mod varint;
+
 pub mod write;
 pub(crate) use write::*;
 
@@ -53,6 +53,11 @@ pub struct GlobalDispatcherHandle {
 }
 
 impl GlobalDispatcherHandle {
+    /// Whether the global dispatcher critical section is held
+    pub(crate) fn is_acquired(&self) -> bool {
+        self.restore_state.is_some()
+    }
+
     pub fn get(&mut self, f: impl FnOnce(&mut dyn Dispatcher)) {
         // SAFETY:
         // Inner handle only set to `Option::Some` if in initialized state, and initialized state is
@@ -0,0 +1,140 @@
+use core::sync::atomic::{AtomicU8, AtomicU32, Ordering};
+
+use crate::*;
+
+static GLOBAL_MAX_LEVEL: AtomicU8 = AtomicU8::new(LevelFilter::Trace as u8);
+
+// Each slot packs an occupied bit, a crate ID and a level filter. Slots are
+// claimed in order and never released, which allows lookups to stop at the
+// first empty slot.
+static CRATE_MAX_LEVELS: [AtomicU32; MAX_CRATE_FILTERS] = [const { AtomicU32::new(EMPTY_SLOT) }; MAX_CRATE_FILTERS];
+
+pub const MAX_CRATE_FILTERS: usize = 16;
+
+const EMPTY_SLOT: u32 = 0;
+const OCCUPIED_BIT: u32 = 1 << 24;
+// Crate filter reset to use the global max level
+const INHERIT_FILTER: u8 = u8::MAX;
+
+pub struct GlobalFilter;
+
+impl GlobalFilter {
+    pub fn set_max_level(level_filter: LevelFilter) {
+        GLOBAL_MAX_LEVEL.store(level_filter as u8, Ordering::Relaxed);
+    }
+
+    pub fn max_level() -> LevelFilter {
+        LevelFilter::from_repr(GLOBAL_MAX_LEVEL.load(Ordering::Relaxed)).unwrap_or(LevelFilter::Trace)
+    }
+
+    pub fn set_crate_max_level(crate_id: CrateId, level_filter: LevelFilter) -> Result<(), GlobalLoggerError> {
+        Self::set_crate_filter(crate_id, level_filter as u8)
+    }
+
+    pub fn reset_crate_max_level(crate_id: CrateId) {
+        // Never fails since unregistered crates need not be reset
+        if Self::find_crate_slot(crate_id).is_some() {
+            let _ = Self::set_crate_filter(crate_id, INHERIT_FILTER);
+        }
+    }
+
+    pub fn enabled(crate_id: CrateId, level: Level) -> bool {
+        let level_filter = Self::find_crate_slot(crate_id)
+            .map(|slot| unpack_slot(slot.load(Ordering::Relaxed)).1)
+            .and_then(LevelFilter::from_repr)
+            .unwrap_or_else(Self::max_level);
+
+        level_filter.enables(level)
+    }
+
+    fn set_crate_filter(crate_id: CrateId, filter: u8) -> Result<(), GlobalLoggerError> {
+        let packed_slot = pack_slot(crate_id, filter);
+
+        for slot in &CRATE_MAX_LEVELS {
+            let claim_result = slot.compare_exchange(EMPTY_SLOT, packed_slot, Ordering::AcqRel, Ordering::Acquire);
+
+            let Err(current_slot) = claim_result else {
+                return Ok(());
+            };
+
+            if unpack_slot(current_slot).0 == crate_id {
+                slot.store(packed_slot, Ordering::Release);
+                return Ok(());
+            }
+        }
+
+        Err(GlobalLoggerError::CrateFiltersExhausted)
+    }
+
+    fn find_crate_slot(crate_id: CrateId) -> Option<&'static AtomicU32> {
+        for slot in &CRATE_MAX_LEVELS {
+            let current_slot = slot.load(Ordering::Acquire);
+
+            if current_slot == EMPTY_SLOT {
+                return None;
+            }
+
+            if unpack_slot(current_slot).0 == crate_id {
+                return Some(slot);
+            }
+        }
+
+        None
+    }
+}
+
+fn pack_slot(crate_id: CrateId, filter: u8) -> u32 {
+    OCCUPIED_BIT | (*crate_id.as_ref() as u32) << 8 | filter as u32
+}
+
+fn unpack_slot(slot: u32) -> (CrateId, u8) {
+    let crate_id = CrateId::new((slot >> 8) as u16);
+    let filter = slot as u8;
+    (crate_id, filter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Filters are global, hence a single test to avoid any interference.
+    #[test]
+    fn filtering() {
+        let crate_id = CrateId::new(1);
+        let other_crate_id = CrateId::new(2);
+
+        assert_eq!(LevelFilter::Trace, GlobalFilter::max_level());
+        assert!(GlobalFilter::enabled(crate_id, Level::Trace));
+
+        GlobalFilter::set_max_level(LevelFilter::Warn);
+        assert!(!GlobalFilter::enabled(crate_id, Level::Info));
+        assert!(GlobalFilter::enabled(crate_id, Level::Warn));
+
+        // crate override
+        GlobalFilter::set_crate_max_level(crate_id, LevelFilter::Debug).unwrap();
+        assert!(GlobalFilter::enabled(crate_id, Level::Debug));
+        assert!(!GlobalFilter::enabled(crate_id, Level::Trace));
+        assert!(!GlobalFilter::enabled(other_crate_id, Level::Info));
+
+        // override replaced
+        GlobalFilter::set_crate_max_level(crate_id, LevelFilter::Off).unwrap();
+        assert!(!GlobalFilter::enabled(crate_id, Level::Error));
+
+        // override reset
+        GlobalFilter::reset_crate_max_level(crate_id);
+        assert!(GlobalFilter::enabled(crate_id, Level::Warn));
+        assert!(!GlobalFilter::enabled(crate_id, Level::Info));
+
+        // slot reused for previously registered crate
+        for id in 2..(MAX_CRATE_FILTERS as u16 + 1) {
+            GlobalFilter::set_crate_max_level(CrateId::new(id), LevelFilter::Trace).unwrap();
+        }
+        GlobalFilter::set_crate_max_level(crate_id, LevelFilter::Trace).unwrap();
+
+        let error = GlobalFilter::set_crate_max_level(CrateId::new(u16::MAX), LevelFilter::Trace).unwrap_err();
+        assert!(matches!(error, GlobalLoggerError::CrateFiltersExhausted));
+
+        let last_crate_id = CrateId::new(MAX_CRATE_FILTERS as u16);
+        assert!(GlobalFilter::enabled(last_crate_id, Level::Trace));
+    }
+}
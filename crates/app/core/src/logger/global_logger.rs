@@ -1,13 +1,28 @@
+use core::sync::atomic::{AtomicBool, AtomicU16, Ordering};
+
 use crate::*;
 
+static CRC_ENABLED: AtomicBool = AtomicBool::new(false);
+
+static VARINT_ENABLED: AtomicBool = AtomicBool::new(false);
+
+static SEQUENCE_ENABLED: AtomicBool = AtomicBool::new(false);
+// Only loaded and stored while holding the global dispatcher critical
+// section, which already serializes increments.
+static SEQUENCE: AtomicU16 = AtomicU16::new(0);
+
 #[derive(Debug)]
 pub enum GlobalLoggerError {
     StamperAlreadyInitialized,
     LoggerAlreadyInitialized,
+    /// More than [`MAX_CRATE_FILTERS`] crates given a max level
+    CrateFiltersExhausted,
 }
 
 pub struct GlobalLogger {
     handle: GlobalDispatcherHandle,
+    crc: Option<Crc16>,
+    varint: bool,
 }
 
 impl GlobalLogger {
@@ -34,28 +49,123 @@ impl GlobalLogger {
         GlobalDispatcher::init_static(dispatcher)
     }
 
-    // Acquires global dispatcher
+    /// Set the most verbose level to be dispatched
+    ///
+    /// Defaults to [`LevelFilter::Trace`]. Checked before the dispatcher is
+    /// acquired, filtered statements therefore also skip evaluating their
+    /// arguments. Print statements are never filtered.
+    pub fn set_max_level(level_filter: LevelFilter) {
+        GlobalFilter::set_max_level(level_filter);
+    }
+
+    pub fn max_level() -> LevelFilter {
+        GlobalFilter::max_level()
+    }
+
+    /// Override the global max level for log statements within a given crate
+    ///
+    /// At most [`MAX_CRATE_FILTERS`] crates may be given an override.
+    pub fn set_crate_max_level(crate_id: CrateId, level_filter: LevelFilter) -> Result<(), GlobalLoggerError> {
+        GlobalFilter::set_crate_max_level(crate_id, level_filter)
+    }
+
+    /// Reset crate to use the global max level
+    pub fn reset_crate_max_level(crate_id: CrateId) {
+        GlobalFilter::reset_crate_max_level(crate_id);
+    }
+
+    /// Append a CRC-16 trailer to each subsequent frame
+    ///
+    /// Allows decoders to detect corrupted frame contents, such as flipped
+    /// bits in a dispatched value, at the cost of two bytes per frame.
+    pub fn enable_crc() {
+        CRC_ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    /// Write a wrapping frame sequence number after each subsequent header
+    ///
+    /// Allows decoders to detect frames which were lost along the way, for
+    /// example because of a dispatcher ring buffer overflowing.
+    pub fn enable_sequence() {
+        SEQUENCE_ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    /// Write integers, lengths and stamps of each subsequent frame as LEB128 varints
+    ///
+    /// Small values such as counters and collection lengths then shrink to a
+    /// single byte, whereas values close to their type's max grow by one to
+    /// three bytes. Advertised with [`HeaderExtension::VARINT`], which decoders
+    /// unaware of it fail to decode rather than silently misreading values.
+    /// Floats, identifiers, `u8` and `i8` are unaffected.
+    pub fn enable_varint() {
+        VARINT_ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    // Acquires global dispatcher, returns `None` if the statement is filtered out
     //
     // Hidden because it should only be used by print proc-macros
     #[doc(hidden)]
-    pub fn write_start(print_id: (CrateId, PrintStatementId), level: Option<Level>) -> Self {
-        let mut handle = GlobalDispatcher::global_dispatcher();
+    pub fn write_start(print_id: (CrateId, PrintStatementId), level: Option<Level>) -> Option<Self> {
+        let (crate_id, print_statement_id) = print_id;
+
+        if level.is_some_and(|level| !GlobalFilter::enabled(crate_id, level)) {
+            return None;
+        }
+
+        let handle = GlobalDispatcher::global_dispatcher();
 
         let stamper = GlobalStamper::stamper();
 
-        let header = Header::new(stamper.is_some(), level);
-        handle.get(|dispatcher| dispatcher.write(&[header.bits()]));
+        let crc = CRC_ENABLED.load(Ordering::Relaxed).then(Crc16::new);
 
-        if let Some(stamp) = stamper.map(Stamper::stamp) {
-            handle.get(|dispatcher| dispatcher.write(&stamp.as_ref().to_be_bytes()));
+        let varint = VARINT_ENABLED.load(Ordering::Relaxed);
+
+        let mut logger = Self { handle, crc, varint };
+
+        let sequence_enabled = SEQUENCE_ENABLED.load(Ordering::Relaxed);
+
+        let mut header = Header::new(stamper.is_some(), level);
+
+        if logger.crc.is_some() {
+            header |= Header::CRC;
         }
 
-        let (crate_id, print_statement_id) = print_id;
+        if sequence_enabled {
+            header |= Header::SEQUENCE;
+        }
+
+        match varint {
+            true => {
+                let (header, extension) = header.split_extension(HeaderExtension::VARINT);
+                logger.write(&[header.bits(), extension.bits()]);
+            }
+            false => logger.write(&[header.bits()]),
+        }
+
+        if sequence_enabled {
+            // Load and store are only sound while `handle` holds the global
+            // dispatcher critical section. Frames written without a dispatcher
+            // are discarded, and therefore don't advance the sequence.
+            let sequence = SEQUENCE.load(Ordering::Relaxed);
+
+            if logger.handle.is_acquired() {
+                SEQUENCE.store(sequence.wrapping_add(1), Ordering::Relaxed);
+            }
+
+            logger.write(&sequence.to_be_bytes());
+        }
+
+        if let Some(stamp) = stamper.map(Stamper::stamp) {
+            match varint {
+                true => logger.write(varint::encode(*stamp.as_ref() as u128, &mut [0; varint::MAX_LENGTH])),
+                false => logger.write(&stamp.as_ref().to_be_bytes()),
+            }
+        }
 
-        handle.get(|dispatcher| dispatcher.write(&crate_id.as_ref().to_be_bytes()));
-        handle.get(|dispatcher| dispatcher.write(&print_statement_id.as_ref().to_be_bytes()));
+        logger.write(&crate_id.as_ref().to_be_bytes());
+        logger.write(&print_statement_id.as_ref().to_be_bytes());
 
-        Self { handle }
+        Some(logger)
     }
 
     // Dynamic dispatch on `format` to reduce code monoporphization
@@ -63,8 +173,12 @@ impl GlobalLogger {
     // Hidden because it should only be used by print proc-macros
     #[doc(hidden)]
     pub fn write_format(&mut self, format: &dyn Format) {
+        let crc = self.crc.as_mut();
+        let varint = self.varint;
+
         self.handle.get(|dispatcher| {
-            let mut formatter = Formatter::new(dispatcher);
+            let mut frame_dispatcher = FrameDispatcher { inner: dispatcher, crc, varint };
+            let mut formatter = Formatter::new(&mut frame_dispatcher);
             // TODO: do anything with fmt error?
             let _ = format.fmt(&mut formatter);
         });
@@ -74,7 +188,42 @@ impl GlobalLogger {
     //
     // Hidden because it should only be used by print proc-macros
     #[doc(hidden)]
-    pub fn write_end(self) {
+    pub fn write_end(mut self) {
+        if let Some(crc) = self.crc.take() {
+            self.write(&crc.finish().to_be_bytes());
+        }
+
+        self.handle.get(|dispatcher| dispatcher.end_frame());
         drop(self)
     }
+
+    fn write(&mut self, bytes: &[u8]) {
+        if let Some(crc) = &mut self.crc {
+            crc.update(bytes);
+        }
+
+        self.handle.get(|dispatcher| dispatcher.write(bytes));
+    }
+}
+
+// Updates the frame CRC with each value written by `Format` implementations,
+// and tells them whether to write varints
+struct FrameDispatcher<'a> {
+    inner: &'a mut dyn Dispatcher,
+    crc: Option<&'a mut Crc16>,
+    varint: bool,
+}
+
+impl Dispatcher for FrameDispatcher<'_> {
+    fn write(&mut self, bytes: &[u8]) {
+        if let Some(crc) = &mut self.crc {
+            crc.update(bytes);
+        }
+
+        self.inner.write(bytes);
+    }
+
+    fn varint(&self) -> bool {
+        self.varint
+    }
 }
@@ -1,6 +1,10 @@
 mod global_logger;
 pub use global_logger::{GlobalLogger, GlobalLoggerError};
 
+mod global_filter;
+pub(crate) use global_filter::GlobalFilter;
+pub use global_filter::MAX_CRATE_FILTERS;
+
 mod global_stamper;
 pub(crate) use global_stamper::GlobalStamper;
 
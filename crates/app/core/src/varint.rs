@@ -0,0 +1,117 @@
+//! LEB128 variable length integers
+//!
+//! Seven bits are stored per byte, least significant group first, with the
+//! most significant bit set on all but the last byte. Signed integers are
+//! zigzag encoded beforehand so that small negative numbers stay short too.
+
+/// Most bytes needed to encode a `u128`
+pub const MAX_LENGTH: usize = 19;
+
+const CONTINUATION_BIT: u8 = 0b1000_0000;
+
+/// Varint exceeding 128 bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VarintOverflow;
+
+pub fn encode(mut value: u128, buffer: &mut [u8; MAX_LENGTH]) -> &[u8] {
+    let mut length = 0;
+
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            buffer[length] = byte;
+            return &buffer[..=length];
+        }
+
+        buffer[length] = byte | CONTINUATION_BIT;
+        length += 1;
+    }
+}
+
+/// Returns the decoded value along with the number of bytes it spanned
+///
+/// `Ok(None)` if the bytes end before the varint does.
+pub fn decode(bytes: &[u8]) -> Result<Option<(u128, usize)>, VarintOverflow> {
+    let mut value = 0u128;
+
+    for (index, byte) in bytes.iter().enumerate() {
+        let group = (byte & !CONTINUATION_BIT) as u128;
+        let shift = index as u32 * 7;
+
+        if index >= MAX_LENGTH || (group << shift) >> shift != group {
+            return Err(VarintOverflow);
+        }
+
+        value |= group << shift;
+
+        if byte & CONTINUATION_BIT == 0 {
+            return Ok(Some((value, index + 1)));
+        }
+    }
+
+    Ok(None)
+}
+
+pub const fn zigzag_encode(value: i128) -> u128 {
+    ((value << 1) ^ (value >> 127)) as u128
+}
+
+pub const fn zigzag_decode(value: u128) -> i128 {
+    (value >> 1) as i128 ^ -((value & 1) as i128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip(0, &[0x00]);
+        assert_round_trip(3, &[0x03]);
+        assert_round_trip(127, &[0x7f]);
+        assert_round_trip(128, &[0x80, 0x01]);
+        assert_round_trip(300, &[0xac, 0x02]);
+        assert_round_trip(u16::MAX as u128, &[0xff, 0xff, 0x03]);
+
+        let mut buffer = [0; MAX_LENGTH];
+        assert_eq!(MAX_LENGTH, encode(u128::MAX, &mut buffer).len());
+        assert_eq!(Ok(Some((u128::MAX, MAX_LENGTH))), decode(&buffer));
+
+        fn assert_round_trip(value: u128, expected_bytes: &[u8]) {
+            let mut buffer = [0; MAX_LENGTH];
+            let bytes = encode(value, &mut buffer);
+
+            assert_eq!(expected_bytes, bytes);
+            assert_eq!(Ok(Some((value, bytes.len()))), decode(bytes));
+        }
+    }
+
+    #[test]
+    fn incomplete() {
+        assert_eq!(Ok(None), decode(&[]));
+        assert_eq!(Ok(None), decode(&[0x80]));
+    }
+
+    #[test]
+    fn overflow() {
+        let mut bytes = [0xff; MAX_LENGTH];
+        bytes[MAX_LENGTH - 1] = 0x7f;
+        assert_eq!(Err(VarintOverflow), decode(&bytes));
+
+        assert_eq!(Err(VarintOverflow), decode(&[0x80; MAX_LENGTH + 1]));
+    }
+
+    #[test]
+    fn zigzag() {
+        assert_eq!(0, zigzag_encode(0));
+        assert_eq!(1, zigzag_encode(-1));
+        assert_eq!(2, zigzag_encode(1));
+        assert_eq!(u128::MAX, zigzag_encode(i128::MIN));
+
+        for value in [0, -1, 1, i64::MIN as i128, i128::MIN, i128::MAX] {
+            assert_eq!(value, zigzag_decode(zigzag_encode(value)));
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! `Format` implementations of core library enums
+//!
+//! Encoded like their `#[derive(Format)]` equivalents, only identified by a
+//! [`BuiltinTypeStructure`] rather than a registered type structure ID.
+//! Variant indexes follow their declaration order in the core library.
+
+use core::{
+    cmp::Ordering,
+    ops::{Bound, ControlFlow},
+    task::Poll,
+};
+
+use crate::*;
+
+impl<T: Format> Format for Option<T> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write(BuiltinTypeStructure::Option);
+
+        match self {
+            None => {
+                f.write_raw(0usize);
+            }
+            Some(value) => {
+                f.write_raw(1usize);
+                value.fmt(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Format, E: Format> Format for Result<T, E> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write(BuiltinTypeStructure::Result);
+
+        match self {
+            Ok(value) => {
+                f.write_raw(0usize);
+                value.fmt(f)?;
+            }
+            Err(error) => {
+                f.write_raw(1usize);
+                error.fmt(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Format for Ordering {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write(BuiltinTypeStructure::Ordering);
+
+        let variant_index: usize = match self {
+            Ordering::Less => 0,
+            Ordering::Equal => 1,
+            Ordering::Greater => 2,
+        };
+
+        f.write_raw(variant_index);
+
+        Ok(())
+    }
+}
+
+impl<T: Format> Format for Bound<T> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write(BuiltinTypeStructure::Bound);
+
+        match self {
+            Bound::Included(value) => {
+                f.write_raw(0usize);
+                value.fmt(f)?;
+            }
+            Bound::Excluded(value) => {
+                f.write_raw(1usize);
+                value.fmt(f)?;
+            }
+            Bound::Unbounded => {
+                f.write_raw(2usize);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Format> Format for Poll<T> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write(BuiltinTypeStructure::Poll);
+
+        match self {
+            Poll::Ready(value) => {
+                f.write_raw(0usize);
+                value.fmt(f)?;
+            }
+            Poll::Pending => {
+                f.write_raw(1usize);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<B: Format, C: Format> Format for ControlFlow<B, C> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write(BuiltinTypeStructure::ControlFlow);
+
+        match self {
+            ControlFlow::Continue(value) => {
+                f.write_raw(0usize);
+                value.fmt(f)?;
+            }
+            ControlFlow::Break(value) => {
+                f.write_raw(1usize);
+                value.fmt(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn option() {
+        let mut dispatcher = SimpleTestDispatcher::default();
+        Some(1u8).fmt(&mut Formatter::new(&mut dispatcher)).unwrap();
+
+        let mut expected_bytes = alloc::vec![TypeHint::BuiltinTypeStructure as u8, BuiltinTypeStructure::Option as u8];
+        expected_bytes.extend_from_slice(&1usize.to_be_bytes());
+        expected_bytes.extend_from_slice(&[TypeHint::U8 as u8, 1]);
+
+        assert_eq!(expected_bytes.as_slice(), dispatcher.bytes);
+    }
+
+    #[test]
+    fn ordering() {
+        let mut dispatcher = SimpleTestDispatcher::default();
+        Ordering::Greater.fmt(&mut Formatter::new(&mut dispatcher)).unwrap();
+
+        let mut expected_bytes = alloc::vec![
+            TypeHint::BuiltinTypeStructure as u8,
+            BuiltinTypeStructure::Ordering as u8
+        ];
+        expected_bytes.extend_from_slice(&2usize.to_be_bytes());
+
+        assert_eq!(expected_bytes.as_slice(), dispatcher.bytes);
+    }
+}
@@ -0,0 +1,6 @@
+/// Binary blob, pretty printed as an `xxd` style hexdump
+///
+/// Encoded as its length followed by the raw bytes, rather than as a list of
+/// `u8` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bytes<'a>(pub &'a [u8]);
@@ -1,6 +1,17 @@
 mod write_value;
 pub use write_value::WriteValue;
 
+mod builtin_format;
+
+mod bytes;
+pub use bytes::Bytes;
+
+mod pointer;
+pub use pointer::{FormatPointer, ReferencedArgument, ReferencedPointee, ReferencedPointer};
+
+mod raw_value;
+pub use raw_value::RawValue;
+
 mod statement_writer;
 pub use statement_writer::StatementWriter;
 
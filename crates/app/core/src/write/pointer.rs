@@ -0,0 +1,69 @@
+use core::ptr::NonNull;
+
+/// Values which may be formatted with `{:p}`, mirroring the
+/// `core::fmt::Pointer` implementors
+///
+/// Arguments formatted with `{:p}` are written as the pointer address of
+/// the target, and not as the value they point to.
+pub trait FormatPointer {
+    fn as_pointer(&self) -> *const ();
+}
+
+impl<T: ?Sized> FormatPointer for &T {
+    fn as_pointer(&self) -> *const () {
+        (*self as *const T).cast()
+    }
+}
+
+impl<T: ?Sized> FormatPointer for &mut T {
+    fn as_pointer(&self) -> *const () {
+        (&**self as *const T).cast()
+    }
+}
+
+impl<T: ?Sized> FormatPointer for *const T {
+    fn as_pointer(&self) -> *const () {
+        self.cast()
+    }
+}
+
+impl<T: ?Sized> FormatPointer for *mut T {
+    fn as_pointer(&self) -> *const () {
+        self.cast_const().cast()
+    }
+}
+
+impl<T: ?Sized> FormatPointer for NonNull<T> {
+    fn as_pointer(&self) -> *const () {
+        self.as_ptr().cast_const().cast()
+    }
+}
+
+// Identifier arguments are referenced by the format string processor, as
+// are captured identifiers. `&x` is therefore either a pointer `x` passed
+// by reference, or a user written reference to the pointee `x`. Resolved
+// with autoref specialization, preferring `x` if it's a `FormatPointer`.
+#[doc(hidden)]
+pub struct ReferencedArgument<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait ReferencedPointer {
+    fn as_pointer(&self) -> *const ();
+}
+
+impl<T: FormatPointer + ?Sized> ReferencedPointer for ReferencedArgument<'_, T> {
+    fn as_pointer(&self) -> *const () {
+        self.0.as_pointer()
+    }
+}
+
+#[doc(hidden)]
+pub trait ReferencedPointee {
+    fn as_pointer(&self) -> *const ();
+}
+
+impl<T: ?Sized> ReferencedPointee for &ReferencedArgument<'_, T> {
+    fn as_pointer(&self) -> *const () {
+        (self.0 as *const T).cast()
+    }
+}
@@ -0,0 +1,28 @@
+use crate::*;
+
+/// Argument written without its type hint
+///
+/// Used by the statement macros for arguments whose type is known at compile
+/// time, the type hint being stored alongside the statement instead.
+#[doc(hidden)]
+pub struct RawValue<T>(pub T);
+
+impl<T: WriteValue> Format for RawValue<T> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_raw(&self.0);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_type_hint() {
+        let mut dispatcher = SimpleTestDispatcher::default();
+        RawValue::<u16>(300).fmt(&mut Formatter::new(&mut dispatcher)).unwrap();
+
+        assert_eq!(300u16.to_be_bytes().as_slice(), dispatcher.bytes);
+    }
+}
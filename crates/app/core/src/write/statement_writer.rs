@@ -14,8 +14,9 @@ impl<'a, 'b> StatementWriter<'a, 'b> {
     #[doc(hidden)]
     pub fn write_statement_id(&mut self, crate_id: CrateId, write_id: WriteStatementId) {
         self.formatter.write_raw(StatementWriterHint::Continue as u8);
-        self.formatter.write_raw(crate_id.as_ref());
-        self.formatter.write_raw(write_id.as_ref());
+        // Identifiers are never varints
+        self.formatter.dispatcher.write(&crate_id.as_ref().to_be_bytes());
+        self.formatter.dispatcher.write(&write_id.as_ref().to_be_bytes());
     }
 
     #[doc(hidden)]
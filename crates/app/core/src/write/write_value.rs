@@ -1,3 +1,13 @@
+use core::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    num::{
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8, NonZeroU16, NonZeroU32,
+        NonZeroU64, NonZeroU128, NonZeroUsize,
+    },
+    ptr::NonNull,
+    time::Duration,
+};
+
 use crate::*;
 
 pub trait WriteValue: private::Sealed {
@@ -17,6 +27,10 @@ fn write_type_hint(type_hint: TypeHint, dispatcher: &mut dyn Dispatcher) {
     dispatcher.write(&(type_hint as u8).to_be_bytes());
 }
 
+fn write_varint(value: u128, dispatcher: &mut dyn Dispatcher) {
+    dispatcher.write(varint::encode(value, &mut [0; varint::MAX_LENGTH]));
+}
+
 mod private {
     pub trait Sealed {}
 }
@@ -93,9 +107,21 @@ impl WriteValue for (CrateId, TypeStructureId) {
     }
 
     fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+        // Identifiers are never varints
         let (crate_id, statement_id) = self;
-        crate_id.as_ref().write_raw(dispatcher);
-        statement_id.as_ref().write_raw(dispatcher);
+        dispatcher.write(&crate_id.as_ref().to_be_bytes());
+        dispatcher.write(&statement_id.as_ref().to_be_bytes());
+    }
+}
+
+impl_sealed!(BuiltinTypeStructure);
+impl WriteValue for BuiltinTypeStructure {
+    fn hint(&self) -> TypeHint {
+        TypeHint::BuiltinTypeStructure
+    }
+
+    fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+        dispatcher.write(&(*self as u8).to_be_bytes());
     }
 }
 
@@ -119,23 +145,82 @@ macro_rules! num_impl {
     };
 }
 
+// Single byte integers would only grow as varints
 num_impl!(
-    (isize, TypeHint::Isize),
     (i8, TypeHint::I8),
-    (i16, TypeHint::I16),
-    (i32, TypeHint::I32),
-    (i64, TypeHint::I64),
-    (i128, TypeHint::I128),
-    (usize, TypeHint::Usize),
     (u8, TypeHint::U8),
-    (u16, TypeHint::U16),
-    (u32, TypeHint::U32),
-    (u64, TypeHint::U64),
-    (u128, TypeHint::U128),
     (f32, TypeHint::F32),
     (f64, TypeHint::F64),
 );
 
+macro_rules! varint_num_impl {
+    ($(($type:ty, $hint:expr, $to_varint:expr),)*) => {
+        $(
+            impl WriteValue for $type {
+                fn hint(&self) -> TypeHint {
+                    $hint
+                }
+
+                fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+                    match dispatcher.varint() {
+                        true => write_varint($to_varint(*self), dispatcher),
+                        false => dispatcher.write(&self.to_be_bytes()),
+                    }
+                }
+            }
+
+            impl_aux!($type);
+        )*
+    };
+}
+
+varint_num_impl!(
+    (isize, TypeHint::Isize, |num| varint::zigzag_encode(num as i128)),
+    (i16, TypeHint::I16, |num| varint::zigzag_encode(num as i128)),
+    (i32, TypeHint::I32, |num| varint::zigzag_encode(num as i128)),
+    (i64, TypeHint::I64, |num| varint::zigzag_encode(num as i128)),
+    (i128, TypeHint::I128, varint::zigzag_encode),
+    (usize, TypeHint::Usize, |num| num as u128),
+    (u16, TypeHint::U16, |num| num as u128),
+    (u32, TypeHint::U32, |num| num as u128),
+    (u64, TypeHint::U64, |num| num as u128),
+    (u128, TypeHint::U128, |num| num),
+);
+
+macro_rules! non_zero_impl {
+    ($(($type:ty, $hint:expr),)*) => {
+        $(
+            // Encoded like the inner number, as both print the same
+            impl WriteValue for $type {
+                fn hint(&self) -> TypeHint {
+                    $hint
+                }
+
+                fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+                    self.get().write_raw(dispatcher);
+                }
+            }
+
+            impl_aux!($type);
+        )*
+    };
+}
+
+non_zero_impl!(
+    (NonZeroIsize, TypeHint::Isize),
+    (NonZeroI8, TypeHint::I8),
+    (NonZeroI16, TypeHint::I16),
+    (NonZeroI32, TypeHint::I32),
+    (NonZeroI64, TypeHint::I64),
+    (NonZeroI128, TypeHint::I128),
+    (NonZeroUsize, TypeHint::Usize),
+    (NonZeroU8, TypeHint::U8),
+    (NonZeroU16, TypeHint::U16),
+    (NonZeroU32, TypeHint::U32),
+    (NonZeroU64, TypeHint::U64),
+    (NonZeroU128, TypeHint::U128),
+);
+
 impl_aux!(bool);
 impl WriteValue for bool {
     fn hint(&self) -> TypeHint {
@@ -169,11 +254,142 @@ impl WriteValue for &str {
     }
 
     fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
-        dispatcher.write(&self.len().to_be_bytes());
+        self.len().write_raw(dispatcher);
         dispatcher.write(self.as_bytes());
     }
 }
 
+impl_aux!(Duration);
+impl WriteValue for Duration {
+    fn hint(&self) -> TypeHint {
+        TypeHint::Duration
+    }
+
+    fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+        dispatcher.write(&self.as_secs().to_be_bytes());
+        dispatcher.write(&self.subsec_nanos().to_be_bytes());
+    }
+}
+
+impl_aux!(Ipv4Addr);
+impl WriteValue for Ipv4Addr {
+    fn hint(&self) -> TypeHint {
+        TypeHint::Ipv4Addr
+    }
+
+    fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+        dispatcher.write(&self.octets());
+    }
+}
+
+impl_aux!(Ipv6Addr);
+impl WriteValue for Ipv6Addr {
+    fn hint(&self) -> TypeHint {
+        TypeHint::Ipv6Addr
+    }
+
+    fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+        dispatcher.write(&self.octets());
+    }
+}
+
+impl_aux!(IpAddr);
+impl WriteValue for IpAddr {
+    fn hint(&self) -> TypeHint {
+        match self {
+            IpAddr::V4(ip) => ip.hint(),
+            IpAddr::V6(ip) => ip.hint(),
+        }
+    }
+
+    fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+        match self {
+            IpAddr::V4(ip) => ip.write_raw(dispatcher),
+            IpAddr::V6(ip) => ip.write_raw(dispatcher),
+        }
+    }
+}
+
+impl_aux!(SocketAddrV4);
+impl WriteValue for SocketAddrV4 {
+    fn hint(&self) -> TypeHint {
+        TypeHint::SocketAddrV4
+    }
+
+    fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+        self.ip().write_raw(dispatcher);
+        dispatcher.write(&self.port().to_be_bytes());
+    }
+}
+
+impl_aux!(SocketAddrV6);
+impl WriteValue for SocketAddrV6 {
+    fn hint(&self) -> TypeHint {
+        TypeHint::SocketAddrV6
+    }
+
+    fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+        self.ip().write_raw(dispatcher);
+        dispatcher.write(&self.port().to_be_bytes());
+        dispatcher.write(&self.flowinfo().to_be_bytes());
+        dispatcher.write(&self.scope_id().to_be_bytes());
+    }
+}
+
+impl_aux!(SocketAddr);
+impl WriteValue for SocketAddr {
+    fn hint(&self) -> TypeHint {
+        match self {
+            SocketAddr::V4(socket_addr) => socket_addr.hint(),
+            SocketAddr::V6(socket_addr) => socket_addr.hint(),
+        }
+    }
+
+    fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+        match self {
+            SocketAddr::V4(socket_addr) => socket_addr.write_raw(dispatcher),
+            SocketAddr::V6(socket_addr) => socket_addr.write_raw(dispatcher),
+        }
+    }
+}
+
+impl_aux!(Bytes<'_>);
+impl WriteValue for Bytes<'_> {
+    fn hint(&self) -> TypeHint {
+        TypeHint::Bytes
+    }
+
+    fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+        self.0.len().write_raw(dispatcher);
+        dispatcher.write(self.0);
+    }
+}
+
+// Also used for `{:?}`, like the core library
+macro_rules! pointer_impl {
+    ($($t:ty,)*) => {
+        $(
+            impl<T: ?Sized> private::Sealed for $t {}
+
+            impl<T: ?Sized> WriteValue for $t {
+                fn hint(&self) -> TypeHint {
+                    TypeHint::Pointer
+                }
+
+                fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
+                    self.as_pointer().addr().write_raw(dispatcher);
+                }
+            }
+
+            impl<T: ?Sized> Format for $t {
+                impl_format!();
+            }
+        )*
+    };
+}
+
+pointer_impl!(*const T, *mut T, NonNull<T>,);
+
 impl_aux!(T, &[T]);
 impl<T: WriteValue> WriteValue for &[T] {
     fn hint(&self) -> TypeHint {
@@ -181,7 +397,7 @@ impl<T: WriteValue> WriteValue for &[T] {
     }
 
     fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
-        dispatcher.write(&self.len().to_be_bytes());
+        self.len().write_raw(dispatcher);
 
         // NOTE:
         // Type hint need not be repeated for each element,
@@ -214,7 +430,7 @@ impl WriteValue for &[&dyn WriteValue] {
     }
 
     fn write_raw(&self, dispatcher: &mut dyn Dispatcher) {
-        dispatcher.write(&self.len().to_be_bytes());
+        self.len().write_raw(dispatcher);
 
         for element in *self {
             // type hint needs to be written for each element
@@ -322,6 +538,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn varint() {
+        let mut dispatcher = SimpleTestDispatcher { varint: true, ..Default::default() };
+
+        3u64.write_value(&mut dispatcher);
+        (-2i32).write_value(&mut dispatcher);
+        300usize.write_value(&mut dispatcher);
+        200u8.write_value(&mut dispatcher);
+        "ab".write_value(&mut dispatcher);
+
+        let expected_bytes = [
+            TypeHint::U64 as u8,
+            3,
+            TypeHint::I32 as u8,
+            3,
+            TypeHint::Usize as u8,
+            0xac,
+            0x02,
+            TypeHint::U8 as u8,
+            200,
+            TypeHint::StringSlice as u8,
+            2,
+            b'a',
+            b'b',
+        ];
+
+        assert_eq!(expected_bytes.as_slice(), dispatcher.bytes);
+    }
+
+    #[test]
+    fn bytes() {
+        let mut dispatcher = SimpleTestDispatcher::default();
+        Bytes(&[0xde, 0xad]).write_value(&mut dispatcher);
+
+        let mut expected_bytes = alloc::vec![TypeHint::Bytes as u8];
+        expected_bytes.extend_from_slice(&2usize.to_be_bytes());
+        expected_bytes.extend_from_slice(&[0xde, 0xad]);
+
+        assert_eq!(expected_bytes, dispatcher.bytes);
+    }
+
+    #[test]
+    fn pointer() {
+        let value = 1u8;
+        let reference = &value;
+
+        let mut dispatcher = SimpleTestDispatcher::default();
+        reference.as_pointer().write_value(&mut dispatcher);
+
+        let mut expected_bytes = alloc::vec![TypeHint::Pointer as u8];
+        expected_bytes.extend_from_slice(&(reference as *const u8).addr().to_be_bytes());
+
+        assert_eq!(expected_bytes, dispatcher.bytes);
+    }
+
     #[test]
     fn bool() {
         assert_bool(true);
@@ -368,6 +639,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn non_zero() {
+        let mut dispatcher = SimpleTestDispatcher::default();
+        NonZeroU16::new(300).unwrap().write_value(&mut dispatcher);
+
+        let mut expected_dispatcher = SimpleTestDispatcher::default();
+        300u16.write_value(&mut expected_dispatcher);
+
+        assert_eq!(expected_dispatcher.bytes, dispatcher.bytes);
+    }
+
+    #[test]
+    fn duration() {
+        let mut dispatcher = SimpleTestDispatcher::default();
+        Duration::new(3, 500).write_value(&mut dispatcher);
+
+        let mut expected_bytes = BytesMut::new();
+        expected_bytes.put_u8(TypeHint::Duration as u8);
+        expected_bytes.put_u64(3);
+        expected_bytes.put_u32(500);
+
+        assert_eq!(expected_bytes, dispatcher.bytes);
+    }
+
+    #[test]
+    fn ip_addr() {
+        let mut dispatcher = SimpleTestDispatcher::default();
+        IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)).write_value(&mut dispatcher);
+
+        assert_eq!(&[TypeHint::Ipv4Addr as u8, 10, 0, 0, 1], dispatcher.bytes.as_ref());
+
+        let mut dispatcher = SimpleTestDispatcher::default();
+        IpAddr::V6(Ipv6Addr::LOCALHOST).write_value(&mut dispatcher);
+
+        let mut expected_bytes = BytesMut::new();
+        expected_bytes.put_u8(TypeHint::Ipv6Addr as u8);
+        expected_bytes.put_u128(1);
+
+        assert_eq!(expected_bytes, dispatcher.bytes);
+    }
+
+    #[test]
+    fn socket_addr() {
+        let mut dispatcher = SimpleTestDispatcher::default();
+        SocketAddr::from(([10, 0, 0, 1], 80)).write_value(&mut dispatcher);
+
+        assert_eq!(
+            &[TypeHint::SocketAddrV4 as u8, 10, 0, 0, 1, 0, 80],
+            dispatcher.bytes.as_ref()
+        );
+
+        let mut dispatcher = SimpleTestDispatcher::default();
+        SocketAddrV6::new(Ipv6Addr::LOCALHOST, 80, 2, 3).write_value(&mut dispatcher);
+
+        let mut expected_bytes = BytesMut::new();
+        expected_bytes.put_u8(TypeHint::SocketAddrV6 as u8);
+        expected_bytes.put_u128(1);
+        expected_bytes.put_u16(80);
+        expected_bytes.put_u32(2);
+        expected_bytes.put_u32(3);
+
+        assert_eq!(expected_bytes, dispatcher.bytes);
+    }
+
     #[test]
     fn str() {
         assert_str("x");
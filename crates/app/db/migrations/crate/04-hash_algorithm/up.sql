@@ -0,0 +1,6 @@
+-- Version of the algorithm used for the `hash` column of the register tables.
+-- Existing rows were hashed with `std::hash::DefaultHasher`, denoted by 0, and
+-- are rehashed by `DbClient` once the migrations have been applied.
+CREATE TABLE hash_algorithm(version INTEGER NOT NULL);
+
+INSERT INTO hash_algorithm(version) VALUES (0);
@@ -0,0 +1,6 @@
+-- Whether IDs freed by pruning may be reassigned once the autoincrement
+-- sequence has reached `u16::MAX`, see `redefmt_db::gc::IdReuse`. Builds still
+-- referencing a pruned statement would otherwise decode its replacement.
+CREATE TABLE id_reuse(enabled INTEGER NOT NULL);
+
+INSERT INTO id_reuse(enabled) VALUES (0);
@@ -0,0 +1,7 @@
+-- NULL for crates registered before versions were recorded, these keep their
+-- existing IDs and databases.
+ALTER TABLE crate ADD COLUMN version TEXT;
+
+DROP INDEX crate_name;
+
+CREATE UNIQUE INDEX crate_name_version ON crate(name, ifnull(version, ''));
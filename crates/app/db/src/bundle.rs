@@ -0,0 +1,185 @@
+//! Portable statement bundles
+//!
+//! Snapshot of the crates registered in a state directory, meant to be shipped
+//! alongside release artifacts so that they may be decoded on other machines.
+//! A bundle begins with [`BUNDLE_MAGIC`] and a version byte, followed by the
+//! same records as those embedded in target binaries, see [`crate::embedded`].
+
+use std::path::Path;
+
+use redefmt_core::identifiers::CrateId;
+
+use crate::{embedded::*, *};
+
+pub const BUNDLE_MAGIC: &[u8; 7] = b"REDEFMT";
+
+const BUNDLE_VERSION: u8 = 1;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BundleError {
+    #[error("not a redefmt bundle, missing magic bytes")]
+    InvalidMagic,
+    #[error("unsupported bundle version '{0}', expected '{BUNDLE_VERSION}'")]
+    UnsupportedVersion(u8),
+    #[error("crate '{0}' is not registered in the state directory")]
+    UnknownCrate(String),
+    #[error("invalid bundle record")]
+    Record(#[from] EmbeddedRecordError),
+    #[error("database failure")]
+    Db(#[from] DbClientError),
+}
+
+pub struct Bundle;
+
+impl Bundle {
+    /// Export the given crates of a state directory, all if `crate_names` is empty
+    pub fn export(state_dir: &Path, crate_names: &[CrateName<'_>]) -> Result<Vec<u8>, BundleError> {
+        let main_db = DbClient::new_main(state_dir)?;
+
+        let mut crates = main_db.find_all()?;
+
+        if !crate_names.is_empty() {
+            if let Some(unknown_name) = crate_names
+                .iter()
+                .find(|name| !crates.iter().any(|(_, krate)| &krate.name == *name))
+            {
+                return Err(BundleError::UnknownCrate(unknown_name.to_string()));
+            }
+
+            crates.retain(|(_, krate)| crate_names.contains(&krate.name));
+        }
+
+        let mut bundle = BUNDLE_MAGIC.to_vec();
+        bundle.push(BUNDLE_VERSION);
+
+        for (crate_id, krate) in crates {
+            let crate_db = DbClient::new_crate(state_dir, &krate)?;
+
+            export_table::<PrintStatement>(&mut bundle, crate_id, &krate, &crate_db)?;
+            export_table::<WriteStatement>(&mut bundle, crate_id, &krate, &crate_db)?;
+            export_table::<TypeStructure>(&mut bundle, crate_id, &krate, &crate_db)?;
+        }
+
+        Ok(bundle)
+    }
+
+    /// Load a bundle into in-memory crate databases
+    pub fn load(bundle: &[u8]) -> Result<Vec<EmbeddedCrate>, BundleError> {
+        let records = bundle.strip_prefix(BUNDLE_MAGIC).ok_or(BundleError::InvalidMagic)?;
+
+        let Some((&version, records)) = records.split_first() else {
+            return Err(BundleError::InvalidMagic);
+        };
+
+        if version != BUNDLE_VERSION {
+            return Err(BundleError::UnsupportedVersion(version));
+        }
+
+        EmbeddedRecord::load(records).map_err(Into::into)
+    }
+}
+
+fn export_table<T: StatementTable>(
+    bundle: &mut Vec<u8>,
+    crate_id: CrateId,
+    krate: &Crate<'_>,
+    crate_db: &DbClient<CrateDb>,
+) -> Result<(), BundleError>
+where
+    T::Id: AsRef<u16>,
+    DbClient<CrateDb>: Table<T>,
+{
+    let records: Vec<(T::Id, T)> = crate_db.find_all()?;
+
+    for (id, statement) in records {
+        bundle.extend(EmbeddedRecord::encode(crate_id, krate, id, &statement)?);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_load() {
+        let state_dir = tempfile::tempdir().unwrap();
+
+        let crate_ids = ["x", "y"].map(|name| seed_crate(state_dir.path(), name));
+
+        let bundle = Bundle::export(state_dir.path(), &[]).unwrap();
+        let crates = Bundle::load(&bundle).unwrap();
+
+        let loaded_crate_ids = crates.iter().map(|krate| krate.id).collect::<Vec<_>>();
+        assert_eq!(crate_ids.as_slice(), loaded_crate_ids);
+
+        let [krate, _] = crates.as_slice() else {
+            panic!("expected two crates");
+        };
+
+        let print_statements: Vec<(_, PrintStatement)> = krate.db.find_all().unwrap();
+        assert_eq!(vec![PrintStatement::mock()], strip_ids(print_statements));
+
+        let type_structures: Vec<(_, TypeStructure)> = krate.db.find_all().unwrap();
+        assert_eq!(vec![TypeStructure::mock()], strip_ids(type_structures));
+
+        fn strip_ids<I, T>(records: Vec<(I, T)>) -> Vec<T> {
+            records.into_iter().map(|(_, statement)| statement).collect()
+        }
+    }
+
+    #[test]
+    fn export_selected_crates() {
+        let state_dir = tempfile::tempdir().unwrap();
+
+        seed_crate(state_dir.path(), "x");
+        let crate_id = seed_crate(state_dir.path(), "y");
+
+        let crate_name = CrateName::new("y").unwrap();
+        let bundle = Bundle::export(state_dir.path(), &[crate_name]).unwrap();
+        let crates = Bundle::load(&bundle).unwrap();
+
+        let [krate] = crates.as_slice() else {
+            panic!("expected a single crate");
+        };
+
+        assert_eq!(crate_id, krate.id);
+    }
+
+    #[test]
+    fn unknown_crate_error() {
+        let state_dir = tempfile::tempdir().unwrap();
+
+        let crate_name = CrateName::new("x").unwrap();
+        let result = Bundle::export(state_dir.path(), &[crate_name]);
+
+        assert!(matches!(result, Err(BundleError::UnknownCrate(_))));
+    }
+
+    #[test]
+    fn invalid_magic_error() {
+        let result = Bundle::load(b"REDEF");
+        assert!(matches!(result, Err(BundleError::InvalidMagic)));
+    }
+
+    #[test]
+    fn unsupported_version_error() {
+        let mut bundle = BUNDLE_MAGIC.to_vec();
+        bundle.push(BUNDLE_VERSION + 1);
+
+        let result = Bundle::load(&bundle);
+        assert!(matches!(result, Err(BundleError::UnsupportedVersion(_))));
+    }
+
+    fn seed_crate(state_dir: &Path, name: &str) -> CrateId {
+        let main_db = DbClient::new_main(state_dir).unwrap();
+        let krate = Crate::new(CrateName::new(name).unwrap());
+
+        let crate_db = DbClient::new_crate(state_dir, &krate).unwrap();
+        crate_db.insert(&PrintStatement::mock()).unwrap();
+        crate_db.insert(&TypeStructure::mock()).unwrap();
+
+        main_db.insert(&krate).unwrap()
+    }
+}
@@ -22,6 +22,12 @@ pub enum DbClientError {
     Migration(#[from] rusqlite_migration::Error),
     #[error("unable to create crate directory in '{0}'")]
     CrateDir(PathBuf, #[source] std::io::Error),
+    #[error("all {max} IDs of '{0}' are taken, unreferenced statements must be pruned", max = u16::MAX)]
+    IdOverflow(&'static str),
+    #[error("statements hashed with unknown algorithm version '{0}', was the database written by a newer redefmt?")]
+    UnsupportedHashAlgorithm(u8),
+    #[error("embedded record {0} is {1} bytes long, exceeding its length prefix")]
+    EmbeddedFieldLength(&'static str, usize),
 }
 
 impl DbClient<MainDb> {
@@ -35,13 +41,26 @@ impl DbClient<MainDb> {
 
 impl DbClient<CrateDb> {
     /// Applications will normally supply `StateDir::resolve()` as `dir`.
-    pub fn new_crate(dir: &Path, crate_name: &CrateName) -> Result<Self, DbClientError> {
-        let path = CrateDb::path(dir, crate_name)?;
+    pub fn new_crate(dir: &Path, krate: &Crate) -> Result<Self, DbClientError> {
+        let path = CrateDb::path(dir, krate)?;
         Self::init(&path)
     }
 }
 
 impl<D: Db> DbClient<D> {
+    /// Create a database which only lives as long as the client
+    ///
+    /// Used when statements are loaded from elsewhere than the state
+    /// directory, such as the linker section of a target binary.
+    pub fn new_in_memory() -> Result<Self, DbClientError> {
+        let mut connection = Connection::open_in_memory()?;
+
+        D::migrations().to_latest(&mut connection)?;
+        D::migrate_data(&connection)?;
+
+        Ok(Self { connection, marker: PhantomData })
+    }
+
     /// Create a new database client connection
     ///
     /// Creates the database if it does not exist, applies any outstanding
@@ -54,6 +73,7 @@ impl<D: Db> DbClient<D> {
         connection.pragma_update(None, "synchronous", "NORMAL")?;
 
         D::migrations().to_latest(&mut connection)?;
+        D::migrate_data(&connection)?;
 
         Ok(Self { connection, marker: PhantomData })
     }
@@ -114,9 +134,9 @@ mod tests {
     #[test]
     fn new_crate() {
         let temp_dir = tempfile::tempdir().unwrap();
-        let crate_name = CrateName::new("x").unwrap();
+        let krate = Crate::new(CrateName::new("x").unwrap());
 
-        let result = DbClient::new_crate(temp_dir.path(), &crate_name);
+        let result = DbClient::new_crate(temp_dir.path(), &krate);
 
         assert!(result.is_ok())
     }
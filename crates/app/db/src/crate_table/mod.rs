@@ -1,5 +1,8 @@
 mod name;
 pub use name::{CrateName, CrateNameError};
 
+mod version;
+pub use version::{CrateVersion, CrateVersionError};
+
 mod table;
 pub use table::{Crate, CrateTable};
@@ -1,16 +1,25 @@
 use redefmt_core::identifiers::CrateId;
-use rusqlite::OptionalExtension;
+use rusqlite::{OptionalExtension, params};
 
-use crate::*;
+use crate::{gc::IdReuse, *};
 
+/// Crates are identified by both name and version, so that several versions of
+/// the same dependency in one build graph get separate statement databases
 #[derive(Debug, Clone, PartialEq)]
 pub struct Crate<'a> {
     pub name: CrateName<'a>,
+    /// `None` for crates registered before versions were recorded
+    pub version: Option<CrateVersion<'a>>,
 }
 
 impl<'a> Crate<'a> {
     pub fn new(name: CrateName<'a>) -> Self {
-        Self { name }
+        Self { name, version: None }
+    }
+
+    pub fn with_version(mut self, version: CrateVersion<'a>) -> Self {
+        self.version = Some(version);
+        self
     }
 }
 
@@ -22,43 +31,67 @@ impl<'a> Table<Crate<'a>> for DbClient<MainDb> {
     fn find_by_id(&self, id: CrateId) -> Result<Option<Crate<'static>>, DbClientError> {
         let krate = self
             .connection
-            .query_row("SELECT name FROM crate WHERE id = ?1", [id], |res| res.get(0))
-            .optional()?
-            .map(|name| Crate { name });
+            .query_row("SELECT name, version FROM crate WHERE id = ?1", [id], |res| {
+                Ok(Crate { name: res.get(0)?, version: res.get(1)? })
+            })
+            .optional()?;
 
         Ok(krate)
     }
 
-    fn insert(&self, record: &Crate<'_>) -> Result<CrateId, DbClientError> {
-        self.connection
-            .query_row_and_then(
-                "INSERT INTO crate(name) VALUES (?1) RETURNING id",
-                [&record.name],
-                |res| res.get(0),
-            )
+    fn find_all(&self) -> Result<Vec<(CrateId, Crate<'static>)>, DbClientError> {
+        let mut prepared_statement = self
+            .connection
+            .prepare("SELECT id, name, version FROM crate ORDER BY id")?;
+
+        prepared_statement
+            .query_map([], |res| {
+                Ok((res.get(0)?, Crate { name: res.get(1)?, version: res.get(2)? }))
+            })?
+            .collect::<Result<_, _>>()
             .map_err(Into::into)
     }
+
+    fn insert(&self, record: &Crate<'_>) -> Result<CrateId, DbClientError> {
+        immediate_transaction(&self.connection, |connection| {
+            // crates are never pruned
+            let id = next_short_id(connection, "crate", IdReuse::Forbid)?;
+
+            connection
+                .query_row_and_then(
+                    "INSERT INTO crate(id, name, version) VALUES (?1, ?2, ?3) RETURNING id",
+                    params![id, &record.name, &record.version],
+                    |res| res.get(0),
+                )
+                .map_err(Into::into)
+        })
+    }
+
+    fn insert_with_id(&self, id: CrateId, record: &Crate<'_>) -> Result<(), DbClientError> {
+        self.connection.execute(
+            "INSERT INTO crate(id, name, version) VALUES (?1, ?2, ?3)",
+            params![id, &record.name, &record.version],
+        )?;
+
+        Ok(())
+    }
 }
 
 pub trait CrateTable {
-    fn find_crate_by_name(&self, name: &CrateName<'_>) -> Result<Option<(CrateId, Crate<'static>)>, DbClientError>;
+    /// Find a crate by both its name and version
+    fn find_crate(&self, krate: &Crate<'_>) -> Result<Option<CrateId>, DbClientError>;
 }
 
 impl CrateTable for DbClient<MainDb> {
-    fn find_crate_by_name(&self, name: &CrateName<'_>) -> Result<Option<(CrateId, Crate<'static>)>, DbClientError> {
-        let krate = self
-            .connection
-            .query_row("SELECT id, name FROM crate WHERE name = ?1", [name], |res| {
-                let id = res.get(0)?;
-                // possibly redundant if name has already been given
-                let name = res.get(1)?;
-
-                Ok((id, name))
-            })
-            .optional()?
-            .map(|(id, name)| (id, Crate { name }));
-
-        Ok(krate)
+    fn find_crate(&self, krate: &Crate<'_>) -> Result<Option<CrateId>, DbClientError> {
+        self.connection
+            .query_row(
+                "SELECT id FROM crate WHERE name = ?1 AND version IS ?2",
+                params![&krate.name, &krate.version],
+                |res| res.get(0),
+            )
+            .optional()
+            .map_err(Into::into)
     }
 }
 
@@ -71,7 +104,7 @@ mod tests {
     }
 
     fn mock_crate_record() -> Crate<'static> {
-        Crate { name: CrateName::new("x").unwrap() }
+        Crate::new(CrateName::new("x").unwrap())
     }
 
     #[test]
@@ -90,6 +123,35 @@ mod tests {
         assert!(found_record.is_none());
     }
 
+    #[test]
+    fn find_all() {
+        let (_dir_guard, db) = DbClient::mock_db();
+
+        let record = mock_crate_record();
+        let other_record = Crate::new(CrateName::new("y").unwrap());
+
+        let id = db.insert(&record).unwrap();
+        let other_id = db.insert(&other_record).unwrap();
+
+        let expected = vec![(id, record), (other_id, other_record)];
+        assert_eq!(expected, db.find_all().unwrap());
+    }
+
+    #[test]
+    fn insert_with_id() {
+        let (_dir_guard, db) = DbClient::mock_db();
+
+        let mock_record = mock_crate_record();
+
+        db.insert_with_id(mock_crate_id(), &mock_record).unwrap();
+
+        let found_record = db.find_by_id(mock_crate_id()).unwrap().unwrap();
+        assert_eq!(mock_record, found_record);
+
+        let duplicate_insert_result = db.insert_with_id(mock_crate_id(), &mock_record);
+        assert!(duplicate_insert_result.is_err());
+    }
+
     #[test]
     fn unique_name_index() {
         let (_dir_guard, db) = DbClient::mock_db();
@@ -104,16 +166,42 @@ mod tests {
     }
 
     #[test]
-    fn find_by_name() {
+    fn legacy_crates_migrated() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let mut connection = rusqlite::Connection::open(MainDb::path(temp_dir.path())).unwrap();
+        MAIN_MIGRATIONS.to_version(&mut connection, 1).unwrap();
+        connection.execute("INSERT INTO crate(name) VALUES ('x')", []).unwrap();
+        drop(connection);
+
+        let db = DbClient::new_main(temp_dir.path()).unwrap();
+
+        let [(id, krate)] = db.find_all().unwrap().try_into().unwrap();
+        assert_eq!(mock_crate_record(), krate);
+        assert_eq!(Some(id), db.find_crate(&krate).unwrap());
+    }
+
+    #[test]
+    fn find_crate() {
         let (_dir_guard, db) = DbClient::mock_db();
 
         let record = mock_crate_record();
+        let versioned_record = mock_crate_record().with_version(CrateVersion::new("1.0.0").unwrap());
+        let other_versioned_record = mock_crate_record().with_version(CrateVersion::new("2.0.0").unwrap());
+
+        assert!(db.find_crate(&record).unwrap().is_none());
 
         let id = db.insert(&record).unwrap();
+        let versioned_id = db.insert(&versioned_record).unwrap();
+        let other_versioned_id = db.insert(&other_versioned_record).unwrap();
 
-        let actual = db.find_crate_by_name(&record.name).unwrap().unwrap();
-        let expected = (id, record);
+        assert_eq!(Some(id), db.find_crate(&record).unwrap());
+        assert_eq!(Some(versioned_id), db.find_crate(&versioned_record).unwrap());
+        assert_eq!(
+            Some(other_versioned_id),
+            db.find_crate(&other_versioned_record).unwrap()
+        );
 
-        assert_eq!(expected, actual);
+        assert_eq!(versioned_record, db.find_by_id(versioned_id).unwrap().unwrap());
     }
 }
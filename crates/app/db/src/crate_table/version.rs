@@ -0,0 +1,101 @@
+use std::{borrow::Cow, fmt::Display};
+
+use rusqlite::{
+    ToSql,
+    types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrateVersion<'a>(Cow<'a, str>);
+
+impl Display for CrateVersion<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl AsRef<str> for CrateVersion<'_> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CrateVersionError {
+    #[error("crate version '{0}' must begin with a digit")]
+    NonDigitStart(Cow<'static, str>),
+    #[error("'{0}' not a valid crate version character in '{1}'")]
+    InvalidChar(char, Cow<'static, str>),
+}
+
+impl<'a> CrateVersion<'a> {
+    /// Loosely enforces the [SemVer] character set
+    ///
+    /// Only alphanumerics, dots, dashes and pluses are allowed, with the first
+    /// character being a digit. Used as a directory name in the crate database
+    /// path, see [`CrateName::new`](crate::crate_table::CrateName::new).
+    ///
+    /// [SemVer]: https://semver.org
+    pub fn new(cow_str: impl Into<Cow<'a, str>>) -> Result<Self, CrateVersionError> {
+        let cow_str = cow_str.into();
+
+        if !cow_str.starts_with(|char: char| char.is_ascii_digit()) {
+            return Err(CrateVersionError::NonDigitStart(Cow::Owned(cow_str.into_owned())));
+        }
+
+        for char in cow_str.chars() {
+            if !['.', '-', '+'].contains(&char) && !char.is_ascii_alphanumeric() {
+                return Err(CrateVersionError::InvalidChar(char, Cow::Owned(cow_str.into_owned())));
+            }
+        }
+
+        Ok(Self(cow_str))
+    }
+}
+
+impl<'a> FromSql for CrateVersion<'a> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let str = value.as_str()?.to_owned();
+
+        Self::new(str).map_err(|e| FromSqlError::Other(Box::new(e)))
+    }
+}
+
+impl<'a> ToSql for CrateVersion<'a> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        self.0.to_sql()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        assert_ok("0.1.0");
+        assert_ok("1.0.0-alpha.1");
+        assert_ok("1.0.0+build.5");
+
+        fn assert_ok(str: &str) {
+            let result = CrateVersion::new(str);
+            assert!(result.is_ok(), "initial str: {str}")
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            CrateVersion::new(""),
+            Err(CrateVersionError::NonDigitStart(_))
+        ));
+        assert!(matches!(
+            CrateVersion::new(".."),
+            Err(CrateVersionError::NonDigitStart(_))
+        ));
+        assert!(matches!(
+            CrateVersion::new("1/../x"),
+            Err(CrateVersionError::InvalidChar('/', _))
+        ));
+    }
+}
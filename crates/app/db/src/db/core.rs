@@ -1,8 +1,16 @@
+use rusqlite::Connection;
 use rusqlite_migration::Migrations;
 
+use crate::*;
+
 #[allow(private_bounds)]
 pub trait Db: private::Sealed {
     fn migrations() -> &'static Migrations<'static>;
+
+    /// Migrations which can't be expressed in SQL, applied after [`Self::migrations`]
+    fn migrate_data(_connection: &Connection) -> Result<(), DbClientError> {
+        Ok(())
+    }
 }
 
 mod private {
@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 
+use rusqlite::Connection;
 use rusqlite_migration::Migrations;
 
 use crate::*;
@@ -10,11 +11,22 @@ impl Db for CrateDb {
     fn migrations() -> &'static Migrations<'static> {
         &CRATE_MIGRATIONS
     }
+
+    fn migrate_data(connection: &Connection) -> Result<(), DbClientError> {
+        rehash_statements(connection)
+    }
 }
 
 impl CrateDb {
-    pub(crate) fn path(dir: &Path, crate_name: &CrateName) -> Result<PathBuf, DbClientError> {
-        let crate_dir = dir.join("crates").join(crate_name.as_ref());
+    /// Versioned crates are placed in a subdirectory of the crate name
+    /// directory, which is otherwise used by crates registered before
+    /// versions were recorded.
+    pub(crate) fn path(dir: &Path, krate: &Crate) -> Result<PathBuf, DbClientError> {
+        let mut crate_dir = dir.join("crates").join(krate.name.as_ref());
+
+        if let Some(version) = &krate.version {
+            crate_dir.push(version.as_ref());
+        }
 
         if !crate_dir.exists() {
             std::fs::create_dir_all(&crate_dir).map_err(|err| DbClientError::CrateDir(crate_dir.clone(), err))?;
@@ -30,12 +42,15 @@ mod tests {
 
     #[test]
     fn path() {
-        let expected = "/tmp/crates/abc/db.sqlite";
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path();
 
-        let dir = Path::new("/tmp");
-        let crate_name = CrateName::new("abc").unwrap();
-        let actual = CrateDb::path(dir, &crate_name).unwrap();
+        let krate = Crate::new(CrateName::new("abc").unwrap());
+        let actual = CrateDb::path(dir, &krate).unwrap();
+        assert_eq!(dir.join("crates/abc/db.sqlite"), actual);
 
-        assert_eq!(expected, actual.as_os_str());
+        let krate = krate.with_version(CrateVersion::new("1.0.0").unwrap());
+        let actual = CrateDb::path(dir, &krate).unwrap();
+        assert_eq!(dir.join("crates/abc/1.0.0/db.sqlite"), actual);
     }
 }
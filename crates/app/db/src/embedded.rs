@@ -0,0 +1,360 @@
+//! Statements embedded in a linker section of the target binary
+//!
+//! Allows decoding without access to the state directory of the build
+//! machine. Every record is self-delimiting so that the linker may
+//! concatenate them in any order:
+//!
+//! | Field              | Size           |
+//! |--------------------|----------------|
+//! | Record version     | 1              |
+//! | Crate ID           | 2              |
+//! | Crate name length  | 1              |
+//! | Crate name         | variable       |
+//! | Crate version len. | 1              |
+//! | Crate version      | variable       |
+//! | Table name length  | 1              |
+//! | Table name         | variable       |
+//! | Statement ID       | 2              |
+//! | Statement length   | 4              |
+//! | Statement JSON     | variable       |
+//!
+//! Integers are big endian. Zero bytes in between records are skipped in case
+//! the linker pads them, hence why record versions start from one. Empty crate
+//! versions denote crates registered before versions were recorded, and are
+//! implied by the first record version which lacked the version fields.
+
+use std::{collections::BTreeMap, hash::Hash};
+
+use redefmt_core::identifiers::{CrateId, PrintStatementId, TypeStructureId, WriteStatementId};
+use serde_json::Value as JsonValue;
+
+use crate::*;
+
+/// Name of the linker section which statements are embedded into
+pub const EMBEDDED_SECTION_NAME: &str = ".redefmt";
+
+const RECORD_VERSION: u8 = 2;
+
+const UNVERSIONED_RECORD_VERSION: u8 = 1;
+
+#[derive(Debug, thiserror::Error)]
+pub enum EmbeddedRecordError {
+    #[error("embedded record at offset {0} is truncated")]
+    Truncated(usize),
+    #[error("unsupported embedded record version '{0}' at offset {1}")]
+    UnsupportedVersion(u8, usize),
+    #[error("invalid UTF-8 in embedded record at offset {0}")]
+    InvalidUtf8(usize),
+    #[error("unknown statement table '{0}'")]
+    UnknownTable(String),
+    #[error("invalid embedded crate name")]
+    CrateName(#[from] CrateNameError),
+    #[error("invalid embedded crate version")]
+    CrateVersion(#[from] CrateVersionError),
+    #[error("crate ID '{0}' embedded with differing crate names or versions")]
+    ConflictingCrate(CrateId),
+    #[error("statement ID '{0}' in '{1}' embedded with differing statements for '{2}'")]
+    ConflictingStatement(u16, &'static str, CrateName<'static>),
+    #[error("statement JSON deserialization error")]
+    Json(#[from] serde_json::Error),
+    #[error("database failure")]
+    Db(#[from] DbClientError),
+}
+
+/// Crate loaded from embedded or bundled records into an in-memory database
+pub struct EmbeddedCrate {
+    pub id: CrateId,
+    pub record: Crate<'static>,
+    pub db: DbClient<CrateDb>,
+}
+
+pub struct EmbeddedRecord;
+
+impl EmbeddedRecord {
+    /// Encode a statement registered under the given crate and statement ID
+    pub fn encode<T: StatementTable>(
+        crate_id: CrateId,
+        krate: &Crate<'_>,
+        id: T::Id,
+        statement: &T,
+    ) -> Result<Vec<u8>, DbClientError>
+    where
+        T::Id: AsRef<u16>,
+    {
+        let json = serde_json::to_vec(statement)?;
+        let crate_name = krate.name.as_ref().as_bytes();
+        let crate_version = krate
+            .version
+            .as_ref()
+            .map(|version| version.as_ref().as_bytes())
+            .unwrap_or_default();
+        let table_name = T::NAME.as_bytes();
+
+        let mut bytes = Vec::with_capacity(12 + crate_name.len() + crate_version.len() + table_name.len() + json.len());
+
+        bytes.push(RECORD_VERSION);
+        bytes.extend_from_slice(&crate_id.as_ref().to_be_bytes());
+        bytes.push(field_length("crate name", crate_name)?);
+        bytes.extend_from_slice(crate_name);
+        bytes.push(field_length("crate version", crate_version)?);
+        bytes.extend_from_slice(crate_version);
+        bytes.push(field_length("table name", table_name)?);
+        bytes.extend_from_slice(table_name);
+        bytes.extend_from_slice(&id.as_ref().to_be_bytes());
+        bytes.extend_from_slice(&field_length::<u32>("statement", &json)?.to_be_bytes());
+        bytes.extend_from_slice(&json);
+
+        Ok(bytes)
+    }
+
+    /// Load the concatenated records of a linker section
+    ///
+    /// Records may be repeated, as is the case for statements used in more
+    /// than one place, but must otherwise not contradict each other.
+    pub fn load(section: &[u8]) -> Result<Vec<EmbeddedCrate>, EmbeddedRecordError> {
+        let mut crates = BTreeMap::<CrateId, EmbeddedCrate>::new();
+        let mut reader = RecordReader { section, offset: 0 };
+
+        while let Some(version) = reader.next_version() {
+            let record_offset = reader.offset - 1;
+
+            if !(UNVERSIONED_RECORD_VERSION..=RECORD_VERSION).contains(&version) {
+                return Err(EmbeddedRecordError::UnsupportedVersion(version, record_offset));
+            }
+
+            let crate_id = CrateId::new(reader.u16()?);
+
+            let mut record = Crate::new(CrateName::new(reader.str_u8()?.to_string())?);
+
+            if version != UNVERSIONED_RECORD_VERSION {
+                let crate_version = reader.str_u8()?;
+
+                if !crate_version.is_empty() {
+                    record = record.with_version(CrateVersion::new(crate_version.to_string())?);
+                }
+            }
+            let table_name = reader.str_u8()?;
+            let statement_id = reader.u16()?;
+            let json_length = reader.u32()? as usize;
+            let json = serde_json::from_slice::<JsonValue>(reader.take(json_length)?)?;
+
+            let krate = match crates.get(&crate_id) {
+                Some(krate) => {
+                    if krate.record != record {
+                        return Err(EmbeddedRecordError::ConflictingCrate(crate_id));
+                    }
+
+                    krate
+                }
+                None => {
+                    let krate = EmbeddedCrate { id: crate_id, record, db: DbClient::new_in_memory()? };
+
+                    crates.entry(crate_id).or_insert(krate)
+                }
+            };
+
+            if table_name == PrintStatement::NAME {
+                insert_statement::<PrintStatement>(krate, PrintStatementId::new(statement_id), json)?;
+            } else if table_name == WriteStatement::NAME {
+                insert_statement::<WriteStatement>(krate, WriteStatementId::new(statement_id), json)?;
+            } else if table_name == TypeStructure::NAME {
+                insert_statement::<TypeStructure>(krate, TypeStructureId::new(statement_id), json)?;
+            } else {
+                return Err(EmbeddedRecordError::UnknownTable(table_name.to_string()));
+            }
+        }
+
+        Ok(crates.into_values().collect())
+    }
+}
+
+// Truncated lengths would misalign every record that follows in the section
+fn field_length<L: TryFrom<usize>>(field: &'static str, bytes: &[u8]) -> Result<L, DbClientError> {
+    L::try_from(bytes.len()).map_err(|_| DbClientError::EmbeddedFieldLength(field, bytes.len()))
+}
+
+fn insert_statement<T: StatementTable>(
+    krate: &EmbeddedCrate,
+    id: T::Id,
+    json: JsonValue,
+) -> Result<(), EmbeddedRecordError>
+where
+    T::Id: Copy + AsRef<u16> + Hash,
+    DbClient<CrateDb>: Table<T>,
+{
+    let statement = T::deserialize(json)?;
+
+    match krate.db.find_by_id(id)? {
+        Some(existing_statement) if existing_statement == statement => Ok(()),
+        Some(_) => Err(EmbeddedRecordError::ConflictingStatement(
+            *id.as_ref(),
+            T::NAME,
+            krate.record.name.clone(),
+        )),
+        None => krate.db.insert_with_id(id, &statement).map_err(Into::into),
+    }
+}
+
+struct RecordReader<'a> {
+    section: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> RecordReader<'a> {
+    /// Skips any padding, returns `None` once the section is exhausted
+    fn next_version(&mut self) -> Option<u8> {
+        while let Some(&byte) = self.section.get(self.offset) {
+            self.offset += 1;
+
+            if byte != 0 {
+                return Some(byte);
+            }
+        }
+
+        None
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], EmbeddedRecordError> {
+        let bytes = self
+            .section
+            .get(self.offset..self.offset + length)
+            .ok_or(EmbeddedRecordError::Truncated(self.offset))?;
+
+        self.offset += length;
+
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16, EmbeddedRecordError> {
+        self.take(2).map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, EmbeddedRecordError> {
+        self.take(4)
+            .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn str_u8(&mut self) -> Result<&'a str, EmbeddedRecordError> {
+        let offset = self.offset;
+        let length = self.take(1)?[0] as usize;
+
+        std::str::from_utf8(self.take(length)?).map_err(|_| EmbeddedRecordError::InvalidUtf8(offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_crate() -> Crate<'static> {
+        Crate::new(CrateName::new("x").unwrap()).with_version(CrateVersion::new("1.0.0").unwrap())
+    }
+
+    fn mock_record<T: StatementTableTest>(statement: &T) -> Vec<u8>
+    where
+        T::Id: AsRef<u16>,
+    {
+        EmbeddedRecord::encode(CrateId::new(1), &mock_crate(), T::mock_id(), statement).unwrap()
+    }
+
+    #[test]
+    fn encode_load() {
+        let print_statement = PrintStatement::mock();
+        let type_structure = TypeStructure::mock();
+
+        let mut section = mock_record(&print_statement);
+        // linker padding
+        section.extend_from_slice(&[0, 0]);
+        section.extend(mock_record(&type_structure));
+        // repeated
+        section.extend(mock_record(&print_statement));
+
+        let crates = EmbeddedRecord::load(&section).unwrap();
+
+        let [krate] = crates.as_slice() else {
+            panic!("expected a single crate");
+        };
+
+        assert_eq!(CrateId::new(1), krate.id);
+        assert_eq!(mock_crate(), krate.record);
+
+        let actual_print_statement: Option<PrintStatement> = krate.db.find_by_id(PrintStatement::mock_id()).unwrap();
+        assert_eq!(Some(print_statement), actual_print_statement);
+
+        let actual_type_structure: Option<TypeStructure> = krate.db.find_by_id(TypeStructure::mock_id()).unwrap();
+        assert_eq!(Some(type_structure), actual_type_structure);
+    }
+
+    #[test]
+    fn unversioned_record() {
+        let mut section = mock_record(&WriteStatement::mock());
+
+        // strip crate version of record version 2
+        section[0] = UNVERSIONED_RECORD_VERSION;
+        let version_index = 4 + mock_crate().name.as_ref().len();
+        let version_length = section[version_index] as usize;
+        section.drain(version_index..version_index + 1 + version_length);
+
+        let crates = EmbeddedRecord::load(&section).unwrap();
+
+        assert_eq!(Crate::new(CrateName::new("x").unwrap()), crates[0].record);
+    }
+
+    #[test]
+    fn conflicting_crate_error() {
+        let mut section = mock_record(&WriteStatement::mock());
+
+        let other_crate = Crate::new(CrateName::new("x").unwrap());
+        section.extend(
+            EmbeddedRecord::encode(
+                CrateId::new(1),
+                &other_crate,
+                TypeStructure::mock_id(),
+                &TypeStructure::mock(),
+            )
+            .unwrap(),
+        );
+
+        let result = EmbeddedRecord::load(&section);
+
+        assert!(matches!(result, Err(EmbeddedRecordError::ConflictingCrate(_))));
+    }
+
+    #[test]
+    fn field_length_error() {
+        let build_metadata = "a".repeat(u8::MAX as usize);
+        let long_version = CrateVersion::new(format!("1.0.0+{build_metadata}")).unwrap();
+        let krate = Crate::new(CrateName::new("x").unwrap()).with_version(long_version);
+
+        let result = EmbeddedRecord::encode(
+            CrateId::new(1),
+            &krate,
+            WriteStatement::mock_id(),
+            &WriteStatement::mock(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(DbClientError::EmbeddedFieldLength("crate version", 261))
+        ));
+    }
+
+    #[test]
+    fn truncated_error() {
+        let section = mock_record(&WriteStatement::mock());
+
+        let result = EmbeddedRecord::load(&section[..section.len() - 1]);
+
+        assert!(matches!(result, Err(EmbeddedRecordError::Truncated(_))));
+    }
+
+    #[test]
+    fn conflicting_statement_error() {
+        let mut section = mock_record(&WriteStatement::mock());
+        section.extend(mock_record(&WriteStatement::mock_other()));
+
+        let result = EmbeddedRecord::load(&section);
+
+        assert!(matches!(result, Err(EmbeddedRecordError::ConflictingStatement(..))));
+    }
+}
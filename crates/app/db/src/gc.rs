@@ -0,0 +1,281 @@
+//! Statement garbage collection
+//!
+//! Statement tables only ever grow, every edited format string inserting a new
+//! row. Pruning removes the rows which are no longer referenced by a build,
+//! typically those embedded in its target binaries, see [`crate::embedded`].
+//!
+//! Statements that are still referenced keep their IDs, as these are compiled
+//! into existing builds. Builds still referencing pruned statements, such as
+//! older firmware in the field, can then no longer be decoded with the state
+//! directory, but can be with a bundle exported before pruning.
+//!
+//! Pruned IDs are never reassigned unless opted into with [`IdReuse::Allow`],
+//! in which case they are reused once a table has run out of fresh ones.
+//! **Reused IDs make old builds misdecode:** a build referencing a pruned
+//! statement is decoded with whichever statement took over its ID, printing
+//! wrong output rather than failing. Only allow reuse when no such build will
+//! ever be decoded with the state directory again.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use rusqlite::{Connection, params};
+
+use crate::{embedded::EmbeddedCrate, *};
+
+/// Statement IDs referenced by one or more builds, grouped by crate
+#[derive(Debug, Default)]
+pub struct ReferencedStatements {
+    crates: Vec<(Crate<'static>, CrateReferences)>,
+}
+
+impl ReferencedStatements {
+    /// Add the statements of crates loaded from embedded or bundled records
+    pub fn extend(&mut self, crates: &[EmbeddedCrate]) -> Result<(), DbClientError> {
+        for krate in crates {
+            let references = self.crate_references_mut(&krate.record);
+
+            references.extend::<PrintStatement>(&krate.db)?;
+            references.extend::<WriteStatement>(&krate.db)?;
+            references.extend::<TypeStructure>(&krate.db)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn crate_references_mut(&mut self, krate: &Crate<'static>) -> &mut CrateReferences {
+        let index = match self.crates.iter().position(|(record, _)| record == krate) {
+            Some(index) => index,
+            None => {
+                self.crates.push((krate.clone(), CrateReferences::default()));
+                self.crates.len() - 1
+            }
+        };
+
+        &mut self.crates[index].1
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Crate<'static>, &CrateReferences)> {
+        self.crates.iter().map(|(krate, references)| (krate, references))
+    }
+}
+
+/// Statement IDs of a single crate referenced by one or more builds
+#[derive(Debug, Default)]
+pub struct CrateReferences {
+    tables: BTreeMap<&'static str, BTreeSet<u16>>,
+}
+
+impl CrateReferences {
+    pub fn insert<T: StatementTable>(&mut self, id: T::Id)
+    where
+        T::Id: AsRef<u16>,
+    {
+        self.tables.entry(T::NAME).or_default().insert(*id.as_ref());
+    }
+
+    fn extend<T: StatementTable>(&mut self, db: &DbClient<CrateDb>) -> Result<(), DbClientError>
+    where
+        T::Id: AsRef<u16>,
+        DbClient<CrateDb>: Table<T>,
+    {
+        let records: Vec<(T::Id, T)> = db.find_all()?;
+
+        for (id, _) in records {
+            self.insert::<T>(id);
+        }
+
+        Ok(())
+    }
+
+    fn contains(&self, table: &'static str, id: u16) -> bool {
+        self.tables.get(table).is_some_and(|ids| ids.contains(&id))
+    }
+}
+
+/// Rows of a statement table, reported against the `u16` ID limit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableUsage {
+    pub table: &'static str,
+    pub statements: usize,
+    /// Statements not referenced, and thus pruned by [`StatementGc::prune`]
+    pub unreferenced: usize,
+}
+
+impl Display for TableUsage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let Self { table, statements, unreferenced } = self;
+
+        write!(
+            f,
+            "{table}: {statements}/{} IDs used, {unreferenced} unreferenced",
+            u16::MAX
+        )
+    }
+}
+
+/// Whether IDs freed by [`StatementGc::prune`] may be reassigned, see the [module docs](self)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdReuse {
+    /// Inserts fail with [`DbClientError::IdOverflow`] once a table has run
+    /// out of fresh IDs, default for databases which were never pruned.
+    Forbid,
+    /// Pruned IDs are reassigned once a table has run out of fresh IDs, making
+    /// builds which referenced the pruned statements misdecode.
+    Allow,
+}
+
+impl IdReuse {
+    pub(crate) fn load(connection: &Connection) -> Result<Self, DbClientError> {
+        let enabled: bool = connection.query_row("SELECT enabled FROM id_reuse", [], |res| res.get(0))?;
+
+        Ok(match enabled {
+            true => Self::Allow,
+            false => Self::Forbid,
+        })
+    }
+
+    fn store(self, connection: &Connection) -> Result<(), DbClientError> {
+        connection.execute("UPDATE id_reuse SET enabled = ?1", [self == Self::Allow])?;
+        Ok(())
+    }
+}
+
+pub trait StatementGc {
+    /// Usage of each statement table given the statements still referenced
+    fn usage(&self, referenced: &CrateReferences) -> Result<[TableUsage; 3], DbClientError>;
+
+    /// Delete all statements which are not referenced, returning how many were
+    ///
+    /// `id_reuse` replaces whether the freed IDs, and those freed by any
+    /// previous prune, may be reassigned to new statements.
+    fn prune(&self, referenced: &CrateReferences, id_reuse: IdReuse) -> Result<usize, DbClientError>;
+}
+
+impl StatementGc for DbClient<CrateDb> {
+    fn usage(&self, referenced: &CrateReferences) -> Result<[TableUsage; 3], DbClientError> {
+        Ok([
+            table_usage::<PrintStatement>(self, referenced)?,
+            table_usage::<WriteStatement>(self, referenced)?,
+            table_usage::<TypeStructure>(self, referenced)?,
+        ])
+    }
+
+    fn prune(&self, referenced: &CrateReferences, id_reuse: IdReuse) -> Result<usize, DbClientError> {
+        let transaction = self.connection.unchecked_transaction()?;
+
+        let pruned = prune_table::<PrintStatement>(self, referenced)?
+            + prune_table::<WriteStatement>(self, referenced)?
+            + prune_table::<TypeStructure>(self, referenced)?;
+
+        id_reuse.store(&self.connection)?;
+
+        transaction.commit()?;
+
+        Ok(pruned)
+    }
+}
+
+fn table_usage<T: StatementTable>(
+    db: &DbClient<CrateDb>,
+    referenced: &CrateReferences,
+) -> Result<TableUsage, DbClientError> {
+    let ids = table_ids::<T>(db)?;
+    let unreferenced = ids.iter().filter(|id| !referenced.contains(T::NAME, **id)).count();
+
+    Ok(TableUsage { table: T::NAME, statements: ids.len(), unreferenced })
+}
+
+fn prune_table<T: StatementTable>(
+    db: &DbClient<CrateDb>,
+    referenced: &CrateReferences,
+) -> Result<usize, DbClientError> {
+    let mut prepared_statement = db
+        .connection
+        .prepare(&format!("DELETE FROM {} WHERE id = ?1", T::NAME))?;
+
+    let mut pruned = 0;
+
+    for id in table_ids::<T>(db)? {
+        if !referenced.contains(T::NAME, id) {
+            pruned += prepared_statement.execute(params![id])?;
+        }
+    }
+
+    Ok(pruned)
+}
+
+fn table_ids<T: StatementTable>(db: &DbClient<CrateDb>) -> Result<Vec<u16>, DbClientError> {
+    let mut prepared_statement = db
+        .connection
+        .prepare(&format!("SELECT id FROM {} ORDER BY id", T::NAME))?;
+
+    prepared_statement
+        .query_map([], |res| res.get(0))?
+        .collect::<Result<_, _>>()
+        .map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use redefmt_core::identifiers::CrateId;
+
+    use super::*;
+
+    #[test]
+    fn usage_and_prune() {
+        let (_dir_guard, db) = DbClient::mock_db();
+
+        let print_statement_id = db.insert(&PrintStatement::mock()).unwrap();
+        db.insert(&PrintStatement::mock_other()).unwrap();
+        db.insert(&TypeStructure::mock()).unwrap();
+
+        let mut referenced = CrateReferences::default();
+        referenced.insert::<PrintStatement>(print_statement_id);
+
+        let [print_usage, write_usage, type_structure_usage] = db.usage(&referenced).unwrap();
+        assert_eq!(
+            TableUsage { table: PrintStatement::NAME, statements: 2, unreferenced: 1 },
+            print_usage
+        );
+        assert_eq!(0, write_usage.statements);
+        assert_eq!(1, type_structure_usage.unreferenced);
+
+        assert_eq!(IdReuse::Forbid, IdReuse::load(&db.connection).unwrap());
+        assert_eq!(2, db.prune(&referenced, IdReuse::Allow).unwrap());
+        assert_eq!(IdReuse::Allow, IdReuse::load(&db.connection).unwrap());
+
+        let print_statements: Vec<(_, PrintStatement)> = db.find_all().unwrap();
+        assert_eq!(vec![(print_statement_id, PrintStatement::mock())], print_statements);
+
+        let type_structures: Vec<(_, TypeStructure)> = db.find_all().unwrap();
+        assert!(type_structures.is_empty());
+
+        db.prune(&referenced, IdReuse::Forbid).unwrap();
+        assert_eq!(IdReuse::Forbid, IdReuse::load(&db.connection).unwrap());
+    }
+
+    #[test]
+    fn referenced_embedded_crates() {
+        let krate = Crate::new(CrateName::new("x").unwrap());
+
+        let embedded_db = DbClient::new_in_memory().unwrap();
+        embedded_db
+            .insert_with_id(WriteStatement::mock_id(), &WriteStatement::mock())
+            .unwrap();
+
+        let embedded_crates = [EmbeddedCrate { id: CrateId::new(1), record: krate.clone(), db: embedded_db }];
+
+        let mut referenced = ReferencedStatements::default();
+        referenced.extend(&embedded_crates).unwrap();
+        // repeated builds are merged
+        referenced.extend(&embedded_crates).unwrap();
+
+        let [(referenced_crate, references)] = referenced.iter().collect::<Vec<_>>().try_into().unwrap();
+        assert_eq!(&krate, referenced_crate);
+        assert!(references.contains(WriteStatement::NAME, *WriteStatement::mock_id().as_ref()));
+        assert!(!references.contains(PrintStatement::NAME, *WriteStatement::mock_id().as_ref()));
+    }
+}
@@ -6,7 +6,7 @@
 // foundational client modules
 
 mod state_dir;
-pub use state_dir::{StateDir, StateDirError};
+pub use state_dir::{StateDir, StateDirError, StateDirStrategy};
 
 mod migrations;
 pub(crate) use migrations::{CRATE_MIGRATIONS, MAIN_MIGRATIONS};
@@ -28,5 +28,11 @@ pub(crate) use crate_table::*;
 pub mod statement_table;
 pub(crate) use statement_table::*;
 
+pub mod embedded;
+
+pub mod bundle;
+
+pub mod gc;
+
 mod sql_utils;
 pub(crate) use sql_utils::*;
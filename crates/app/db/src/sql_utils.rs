@@ -34,3 +34,98 @@ mod sql_json {
     }
 }
 pub(crate) use sql_json::{SqlListJsonExt, SqlOptionalJsonExt};
+
+mod short_id {
+    use rusqlite::{Connection, OptionalExtension, Transaction, TransactionBehavior};
+
+    use crate::{gc::IdReuse, *};
+
+    /// Run `f` in an `IMMEDIATE` transaction, committing it if `f` succeeds
+    ///
+    /// The write lock is taken before anything is read, so that concurrent
+    /// compiler processes can't both read the same [`next_short_id`] before
+    /// either of them inserts it.
+    pub fn immediate_transaction<T>(
+        connection: &Connection,
+        f: impl FnOnce(&Connection) -> Result<T, DbClientError>,
+    ) -> Result<T, DbClientError> {
+        let transaction = Transaction::new_unchecked(connection, TransactionBehavior::Immediate)?;
+
+        let output = f(&transaction)?;
+
+        transaction.commit()?;
+
+        Ok(output)
+    }
+
+    /// Explicit ID to insert the next row with, `None` if `AUTOINCREMENT` may be used
+    ///
+    /// IDs are sent as `u16`s, so once the autoincrement sequence reaches
+    /// `u16::MAX` the lowest ID freed by pruning is reused instead, given that
+    /// `id_reuse` allows it. Pruned IDs are therefore only reassigned when fresh
+    /// ones have run out. Should be called within an [`immediate_transaction`]
+    /// along with the insert itself.
+    pub fn next_short_id(
+        connection: &Connection,
+        table: &'static str,
+        id_reuse: IdReuse,
+    ) -> Result<Option<u16>, DbClientError> {
+        let sequence: i64 = connection
+            .query_row("SELECT seq FROM sqlite_sequence WHERE name = ?1", [table], |res| {
+                res.get(0)
+            })
+            .optional()?
+            .unwrap_or_default();
+
+        if sequence < u16::MAX as i64 {
+            return Ok(None);
+        }
+
+        if id_reuse == IdReuse::Forbid {
+            return Err(DbClientError::IdOverflow(table));
+        }
+
+        let free_id = connection
+            .query_row(
+                &format!(
+                    "SELECT candidate FROM (SELECT 1 AS candidate UNION ALL SELECT id + 1 FROM {table})
+                     WHERE candidate <= ?1 AND candidate NOT IN (SELECT id FROM {table})
+                     ORDER BY candidate LIMIT 1"
+                ),
+                [u16::MAX],
+                |res| res.get(0),
+            )
+            .optional()?;
+
+        free_id.map(Some).ok_or(DbClientError::IdOverflow(table))
+    }
+}
+pub(crate) use short_id::{immediate_transaction, next_short_id};
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rusqlite::{Connection, ErrorCode};
+
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn immediate_transaction_locks_before_reading() {
+        let (dir_guard, db) = DbClient::<CrateDb>::mock_db();
+
+        let other_connection = Connection::open(dir_guard.path().join("test.sqlite")).unwrap();
+        other_connection.busy_timeout(Duration::ZERO).unwrap();
+
+        immediate_transaction(&db.connection, |_| {
+            // another compiler process reading the next ID
+            let result = other_connection.execute_batch("BEGIN IMMEDIATE");
+            assert!(matches!(result, Err(err) if err.sqlite_error_code() == Some(ErrorCode::DatabaseBusy)));
+            Ok(())
+        })
+        .unwrap();
+
+        other_connection.execute_batch("BEGIN IMMEDIATE; COMMIT").unwrap();
+    }
+}
@@ -1,4 +1,4 @@
-use std::{fmt::Display, io::Error as IoError, path::PathBuf};
+use std::{env::VarError, fmt::Display, io::Error as IoError, path::PathBuf};
 
 const APPLICATION_NAME: &str = "redefmt";
 
@@ -6,12 +6,56 @@ const XDG_ENV_NAME: &str = "XDG_STATE_HOME";
 
 const OVERRIDE_ENV_NAME: &str = "REDEFMT_STATE";
 
+const STRATEGY_ENV_NAME: &str = "REDEFMT_STATE_STRATEGY";
+
+const CARGO_TARGET_DIR_ENV_NAME: &str = "CARGO_TARGET_DIR";
+
 pub struct StateDir;
 
+/// How the state directory is resolved, see [`StateDirStrategy::from_env`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateDirStrategy {
+    /// Path given by `$REDEFMT_STATE`
+    Explicit(PathBuf),
+    /// `$XDG_STATE_HOME/redefmt`, shared by all projects of the user
+    Global,
+    /// `redefmt` directory in the cargo target directory given by an absolute
+    /// `$CARGO_TARGET_DIR`, set with `relative = true` in `.cargo/config.toml`
+    Target,
+}
+
+impl StateDirStrategy {
+    /// `$REDEFMT_STATE` takes precedence if set, the strategy is otherwise
+    /// selected by setting `$REDEFMT_STATE_STRATEGY` to either `global`
+    /// (default) or `target`.
+    ///
+    /// Note that both the build and the decoder must resolve the same strategy.
+    pub fn from_env() -> Result<Self, StateDirError> {
+        if let Some(override_path) = std::env::var_os(OVERRIDE_ENV_NAME) {
+            return Ok(Self::Explicit(PathBuf::from(override_path)));
+        }
+
+        match std::env::var(STRATEGY_ENV_NAME) {
+            Ok(name) => Self::from_name(&name),
+            Err(VarError::NotPresent) => Ok(Self::Global),
+            Err(VarError::NotUnicode(name)) => Err(StateDirError::UnknownStrategy(name.to_string_lossy().into_owned())),
+        }
+    }
+
+    fn from_name(name: &str) -> Result<Self, StateDirError> {
+        match name {
+            "global" => Ok(Self::Global),
+            "target" => Ok(Self::Target),
+            _ => Err(StateDirError::UnknownStrategy(name.to_string())),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum StateDirSource {
     Xdg,
     Env,
+    Target,
 }
 
 impl Display for StateDirSource {
@@ -19,6 +63,7 @@ impl Display for StateDirSource {
         match self {
             StateDirSource::Xdg => write!(f, "{XDG_ENV_NAME}"),
             StateDirSource::Env => write!(f, "{OVERRIDE_ENV_NAME}"),
+            StateDirSource::Target => write!(f, "cargo target directory"),
         }
     }
 }
@@ -27,6 +72,16 @@ impl Display for StateDirSource {
 pub enum StateDirError {
     #[error("failed to load XDG base directories, no HOME directory found")]
     XdgDirLoad,
+    #[error("unknown state directory strategy '{0}' in {STRATEGY_ENV_NAME}, expected 'global' or 'target'")]
+    UnknownStrategy(String),
+    #[error(
+        "{CARGO_TARGET_DIR_ENV_NAME} is not set, set it or {OVERRIDE_ENV_NAME} in the [env] table of .cargo/config.toml with `relative = true`"
+    )]
+    TargetDirNotFound,
+    #[error(
+        "{CARGO_TARGET_DIR_ENV_NAME} must be an absolute path, got {0}; set it in the [env] table of .cargo/config.toml with `relative = true`"
+    )]
+    RelativeTargetDir(PathBuf),
     #[error("path may not be empty, source; {0}")]
     EmptyPath(StateDirSource),
     #[error("path must be a valid directory; resolved {0} from source {1}")]
@@ -42,23 +97,55 @@ pub enum StateDirError {
 }
 
 impl StateDir {
-    /// Resolves `redefmt`'s state directory
+    /// Resolves `redefmt`'s state directory with the strategy given by
+    /// [`StateDirStrategy::from_env`]
     ///
     /// The returned `PathBuf` can be expected to not be empty, exist, be in
     /// canonical form, and a directory.
     pub fn resolve() -> Result<PathBuf, StateDirError> {
-        match std::env::var_os(OVERRIDE_ENV_NAME) {
-            Some(override_path) => {
-                let path_buf = PathBuf::from(override_path);
-                Self::prepare_state_directory(path_buf, StateDirSource::Env)
+        Self::resolve_with(&StateDirStrategy::from_env()?)
+    }
+
+    /// Resolves `redefmt`'s state directory with the given strategy, see [`Self::resolve`]
+    pub fn resolve_with(strategy: &StateDirStrategy) -> Result<PathBuf, StateDirError> {
+        match strategy {
+            StateDirStrategy::Explicit(path_buf) => {
+                Self::prepare_state_directory(path_buf.clone(), StateDirSource::Env)
             }
-            None => {
+            StateDirStrategy::Global => {
                 let xdg_base_dirs = xdg::BaseDirectories::with_prefix(APPLICATION_NAME)
                     .get_state_home()
                     .ok_or(StateDirError::XdgDirLoad)?;
 
                 Self::prepare_state_directory(xdg_base_dirs, StateDirSource::Xdg)
             }
+            StateDirStrategy::Target => {
+                let cargo_target_dir = std::env::var_os(CARGO_TARGET_DIR_ENV_NAME).map(PathBuf::from);
+                let target_dir = Self::find_target_dir(cargo_target_dir)?;
+
+                Self::prepare_state_directory(target_dir.join(APPLICATION_NAME), StateDirSource::Target)
+            }
+        }
+    }
+
+    /// Cargo target directory given by `$CARGO_TARGET_DIR`, which must be absolute
+    ///
+    /// Cargo doesn't pass its target directory on to the compiler, nor does it run it from the
+    /// workspace root when building dependencies. A relative path would therefore resolve to a
+    /// different directory for each dependency. The variable should instead be set in the
+    /// `[env]` table of `.cargo/config.toml` with `relative = true`, making it an absolute path
+    /// for every crate of the build:
+    ///
+    /// ```toml
+    /// [env]
+    /// CARGO_TARGET_DIR = { value = "target", relative = true }
+    /// ```
+    fn find_target_dir(cargo_target_dir: Option<PathBuf>) -> Result<PathBuf, StateDirError> {
+        let cargo_target_dir = cargo_target_dir.ok_or(StateDirError::TargetDirNotFound)?;
+
+        match cargo_target_dir.is_absolute() {
+            true => Ok(cargo_target_dir),
+            false => Err(StateDirError::RelativeTargetDir(cargo_target_dir)),
         }
     }
 
@@ -131,6 +218,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn strategy_names() {
+        assert_eq!(StateDirStrategy::Global, StateDirStrategy::from_name("global").unwrap());
+        assert_eq!(StateDirStrategy::Target, StateDirStrategy::from_name("target").unwrap());
+
+        let result = StateDirStrategy::from_name("local");
+        assert!(matches!(result, Err(StateDirError::UnknownStrategy(_))));
+    }
+
+    #[test]
+    fn target_dir() {
+        // `[env]` values with `relative = true` are absolute
+        let workspace_target_dir = std::env::temp_dir().join("target");
+        let actual = StateDir::find_target_dir(Some(workspace_target_dir.clone())).unwrap();
+        assert_eq!(workspace_target_dir, actual);
+
+        let result = StateDir::find_target_dir(None);
+        assert!(matches!(result, Err(StateDirError::TargetDirNotFound)));
+
+        // would resolve against each dependency's package root
+        let result = StateDir::find_target_dir(Some("target".into()));
+        assert!(matches!(result, Err(StateDirError::RelativeTargetDir(path)) if path.as_os_str() == "target"));
+    }
+
     #[test]
     fn valid_if_some() {
         let temp_dir = tempfile::tempdir().unwrap();
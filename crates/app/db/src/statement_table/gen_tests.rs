@@ -10,6 +10,7 @@ macro_rules! statement_table_tests {
     ($statement_table:ident) => {
         mod statement_table {
             use super::*;
+            use crate::gc::{CrateReferences, IdReuse, StatementGc};
 
             #[test]
             fn find_none_by_id() {
@@ -53,6 +54,42 @@ macro_rules! statement_table_tests {
                 assert_eq!(first_id_other, second_id_other);
             }
 
+            #[test]
+            fn find_all() {
+                let (_dir_guard, db) = DbClient::mock_db();
+
+                let statement = $statement_table::mock();
+                let other_statement = $statement_table::mock_other();
+
+                let id = insert_helper(&db, &statement);
+                let other_id = insert_helper(&db, &other_statement);
+
+                let actual_records: Vec<(_, $statement_table)> = db.find_all().unwrap();
+                let expected_records = vec![(id, statement), (other_id, other_statement)];
+
+                assert_eq!(expected_records, actual_records);
+            }
+
+            #[test]
+            fn insert_with_id() {
+                let (_dir_guard, db) = DbClient::mock_db();
+
+                let mock_id = $statement_table::mock_id();
+                let statement = $statement_table::mock();
+
+                db.insert_with_id(mock_id, &statement).unwrap();
+
+                let returned_statement = find_helper(&db, mock_id).unwrap();
+                assert_eq!(statement, returned_statement);
+
+                // subsequent inserts continue from the given ID
+                let other_id = insert_helper(&db, &$statement_table::mock_other());
+                assert!(other_id > mock_id);
+
+                let duplicate_insert_result = db.insert_with_id(mock_id, &$statement_table::mock_other());
+                assert!(duplicate_insert_result.is_err());
+            }
+
             #[test]
             fn insert_collisioned() {
                 let (_dir_guard, db) = DbClient::mock_db();
@@ -60,7 +97,7 @@ macro_rules! statement_table_tests {
                 let statement = $statement_table::mock();
                 let statement_other = $statement_table::mock_other();
 
-                let statement_hash = Hash::new(&statement);
+                let statement_hash = Hash::new(&statement).unwrap();
 
                 let other_id = insert_unchecked(&db, statement_hash, &statement_other).unwrap();
 
@@ -82,8 +119,8 @@ macro_rules! statement_table_tests {
                 let statement = $statement_table::mock();
                 let statement_other = $statement_table::mock_other();
 
-                let statement_hash = Hash::new(&statement);
-                let statement_hash_other = Hash::new(&statement_other);
+                let statement_hash = Hash::new(&statement).unwrap();
+                let statement_hash_other = Hash::new(&statement_other).unwrap();
 
                 let id = insert_unchecked(&db, statement_hash, &statement).unwrap();
                 let other_id = insert_unchecked(&db, statement_hash, &statement_other).unwrap();
@@ -101,6 +138,52 @@ macro_rules! statement_table_tests {
                 assert_eq!(expected_records, actual_records);
             }
 
+            #[test]
+            fn insert_reuses_free_ids() {
+                let (_dir_guard, db) = DbClient::mock_db();
+
+                let first_id = insert_helper(&db, &$statement_table::mock());
+                exhaust_sequence(&db);
+
+                let result = db.insert(&$statement_table::mock_other());
+                assert!(matches!(result, Err(DbClientError::IdOverflow(_))));
+
+                db.prune(&CrateReferences::default(), IdReuse::Allow).unwrap();
+                let other_id = insert_helper(&db, &$statement_table::mock_other());
+
+                // first ID freed by pruning
+                assert_eq!(first_id, other_id);
+            }
+
+            #[test]
+            fn insert_id_overflow_error() {
+                let (_dir_guard, db) = DbClient::mock_db();
+
+                db.connection
+                    .execute(
+                        &format!(
+                            "WITH RECURSIVE ids(id) AS (SELECT 1 UNION ALL SELECT id + 1 FROM ids WHERE id < ?1)
+                             INSERT INTO {} (id, hash, statement) SELECT id, 0, jsonb('null') FROM ids",
+                            $statement_table::NAME
+                        ),
+                        [u16::MAX],
+                    )
+                    .unwrap();
+
+                let result = db.insert(&$statement_table::mock());
+
+                assert!(matches!(result, Err(DbClientError::IdOverflow(_))));
+            }
+
+            fn exhaust_sequence(db: &DbClient<CrateDb>) {
+                db.connection
+                    .execute(
+                        "UPDATE sqlite_sequence SET seq = ?1 WHERE name = ?2",
+                        rusqlite::params![u16::MAX, $statement_table::NAME],
+                    )
+                    .unwrap();
+            }
+
             fn find_helper(
                 db: &DbClient<CrateDb>,
                 id: <$statement_table as StatementTable>::Id,
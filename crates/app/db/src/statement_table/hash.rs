@@ -1,19 +1,76 @@
-use std::hash::{DefaultHasher, Hash as StdHash, Hasher};
+use rusqlite::{Connection, params};
+use serde::Serialize;
 
+use crate::*;
+
+/// Stored in the `hash_algorithm` table, 0 being `std::hash::DefaultHasher`
+pub(crate) const HASH_ALGORITHM_VERSION: u8 = 1;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// FNV-1a hash of the JSON serialization of a statement
+///
+/// Unlike `DefaultHasher`, the output is specified and therefore stable across
+/// Rust releases. Statements contain no maps, so that their serialization is
+/// canonical.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
 #[serde(transparent)]
 pub struct Hash(u64);
 
 impl Hash {
-    pub fn new(object: &impl StdHash) -> Self {
-        let mut hasher = DefaultHasher::new();
-        object.hash(&mut hasher);
-        let inner = hasher.finish();
-        Hash(inner)
+    pub fn new(object: &impl Serialize) -> Result<Self, DbClientError> {
+        let json = serde_json::to_vec(object)?;
+        Ok(Hash(fnv1a(&json)))
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Rehash the rows of every statement table if hashed by a previous algorithm
+pub(crate) fn rehash_statements(connection: &Connection) -> Result<(), DbClientError> {
+    let version: u8 = connection.query_row("SELECT version FROM hash_algorithm", [], |res| res.get(0))?;
+
+    if version == HASH_ALGORITHM_VERSION {
+        return Ok(());
+    }
+
+    if version > HASH_ALGORITHM_VERSION {
+        return Err(DbClientError::UnsupportedHashAlgorithm(version));
+    }
+
+    let transaction = connection.unchecked_transaction()?;
+
+    rehash_table::<PrintStatement>(connection)?;
+    rehash_table::<WriteStatement>(connection)?;
+    rehash_table::<TypeStructure>(connection)?;
+
+    connection.execute("UPDATE hash_algorithm SET version = ?1", [HASH_ALGORITHM_VERSION])?;
+
+    transaction.commit().map_err(Into::into)
+}
+
+fn rehash_table<T: StatementTable>(connection: &Connection) -> Result<(), DbClientError> {
+    let rows: Vec<(i64, T)> = connection
+        .prepare(&format!("SELECT id, json(statement) FROM {}", T::NAME))?
+        .query_map([], |res| Ok((res.get(0)?, res.get(1)?)))?
+        .list_json()?;
+
+    let mut prepared_statement = connection.prepare(&format!("UPDATE {} SET hash = ?1 WHERE id = ?2", T::NAME))?;
+
+    for (id, statement) in rows {
+        prepared_statement.execute(params![Hash::new(&statement)?, id])?;
     }
+
+    Ok(())
 }
 
-/// SQLite uses i64 exclusively. To the u64 output of `Hasher::finish`,
+/// SQLite uses i64 exclusively. To the u64 output of the hash function,
 /// we transmute the bytes to and from the signed counterpart.
 mod sql {
     use rusqlite::{
@@ -75,16 +132,69 @@ mod sql {
 mod tests {
     use super::*;
 
-    #[derive(Hash)]
+    #[derive(Serialize)]
     struct X(u64);
 
     #[test]
     fn hash() {
-        let first = Hash::new(&X(1));
-        let second = Hash::new(&X(1));
-        let third = Hash::new(&X(2));
+        let first = Hash::new(&X(1)).unwrap();
+        let second = Hash::new(&X(1)).unwrap();
+        let third = Hash::new(&X(2)).unwrap();
 
         assert_eq!(first, second);
         assert_ne!(second, third);
     }
+
+    #[test]
+    fn fnv1a_vectors() {
+        assert_eq!(0xcbf29ce484222325, fnv1a(b""));
+        assert_eq!(0xaf63dc4c8601ec8c, fnv1a(b"a"));
+        assert_eq!(0x85944171f73967e8, fnv1a(b"foobar"));
+    }
+
+    #[test]
+    fn legacy_hashes_rehashed() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let krate = Crate::new(CrateName::new("x").unwrap());
+
+        let mut connection = Connection::open(CrateDb::path(temp_dir.path(), &krate).unwrap()).unwrap();
+        CRATE_MIGRATIONS.to_version(&mut connection, 3).unwrap();
+        connection
+            .execute(
+                &format!(
+                    "INSERT INTO {} (hash, statement) VALUES (0, jsonb(?1))",
+                    PrintStatement::NAME
+                ),
+                [serde_json::to_value(PrintStatement::mock()).unwrap()],
+            )
+            .unwrap();
+        drop(connection);
+
+        let db = DbClient::new_crate(temp_dir.path(), &krate).unwrap();
+
+        let version: u8 = db
+            .connection
+            .query_row("SELECT version FROM hash_algorithm", [], |res| res.get(0))
+            .unwrap();
+        assert_eq!(HASH_ALGORITHM_VERSION, version);
+
+        let [(id, _)]: [(_, PrintStatement); 1] = db.find_all().unwrap().try_into().unwrap();
+        assert_eq!(id, db.insert(&PrintStatement::mock()).unwrap());
+    }
+
+    #[test]
+    fn unsupported_algorithm_error() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let krate = Crate::new(CrateName::new("x").unwrap());
+
+        let db = DbClient::new_crate(temp_dir.path(), &krate).unwrap();
+        db.connection
+            .execute("UPDATE hash_algorithm SET version = ?1", [HASH_ALGORITHM_VERSION + 1])
+            .unwrap();
+        drop(db);
+
+        let result = DbClient::new_crate(temp_dir.path(), &krate);
+
+        assert!(matches!(result, Err(DbClientError::UnsupportedHashAlgorithm(_))));
+    }
 }
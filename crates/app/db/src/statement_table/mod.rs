@@ -1,5 +1,5 @@
 mod hash;
-pub(crate) use hash::Hash;
+pub(crate) use hash::{Hash, rehash_statements};
 
 mod table;
 pub use table::StatementTable;
@@ -13,6 +13,9 @@ impl StatementTable for PrintStatement<'_> {
 #[derive(Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct PrintStatement<'a> {
     pub location: Location<'a>,
+    /// Optional `target: "..."` argument of log statements
+    #[serde(borrow, default)]
+    pub target: Option<Cow<'a, str>>,
     #[serde(borrow)]
     pub stored_expression: StoredFormatExpression<'a>,
 }
@@ -20,12 +23,26 @@ pub struct PrintStatement<'a> {
 /// Print statement call site location
 ///
 /// Crate could be inferred the crate database itself.
-///
-/// Module path currently not saved since there isn't a reliable way to extract it from proc macros.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct Location<'a> {
     pub file: Cow<'a, str>,
     pub line: u32,
+    /// One-indexed, zero for statements registered before columns were recorded
+    #[serde(default)]
+    pub column: u32,
+    /// Line of the end of the macro invocation
+    #[serde(default)]
+    pub end_line: u32,
+    /// Column of the end of the macro invocation, exclusive
+    #[serde(default)]
+    pub end_column: u32,
+    /// Approximation of `module_path!()` at the call site
+    ///
+    /// Inferred from the file path since proc macros aren't able to extract
+    /// it directly, inline modules are therefore not included. Empty for
+    /// statements registered before module paths were recorded.
+    #[serde(default)]
+    pub module_path: Cow<'a, str>,
 }
 
 #[cfg(test)]
@@ -44,6 +61,7 @@ mod tests {
         fn mock() -> Self {
             PrintStatement {
                 location: mock_location(),
+                target: None,
                 stored_expression: mock_stored_expression(processed_format_string!("x")),
             }
         }
@@ -51,13 +69,30 @@ mod tests {
         fn mock_other() -> Self {
             PrintStatement {
                 location: mock_location(),
+                target: None,
                 stored_expression: mock_stored_expression(processed_format_string!("y")),
             }
         }
     }
 
+    #[test]
+    fn legacy_deserialization() {
+        let location_json = serde_json::json!({ "file": "file.rs", "line": 1 });
+        let location = serde_json::from_value::<Location>(location_json).unwrap();
+
+        assert!(location.module_path.is_empty());
+        assert_eq!(0, location.column);
+    }
+
     fn mock_location() -> Location<'static> {
-        Location { file: "file.rs".into(), line: 1 }
+        Location {
+            file: "file.rs".into(),
+            line: 1,
+            column: 5,
+            end_line: 1,
+            end_column: 20,
+            module_path: "crate::module".into(),
+        }
     }
 
     fn mock_stored_expression(expression: ProcessedFormatString) -> StoredFormatExpression {
@@ -66,6 +101,7 @@ mod tests {
             append_newline: false,
             expected_positional_arg_count: 0,
             expected_named_args: Default::default(),
+            provided_arg_hints: Default::default(),
         }
     }
 }
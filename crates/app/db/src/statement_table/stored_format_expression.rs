@@ -1,4 +1,5 @@
 use redefmt_args::{identifier::AnyIdentifier, processor::ProcessedFormatString};
+use redefmt_core::frame::TypeHint;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct StoredFormatExpression<'a> {
@@ -8,4 +9,9 @@ pub struct StoredFormatExpression<'a> {
     pub expected_positional_arg_count: usize,
     // Order matters, technically unique.
     pub expected_named_args: Vec<AnyIdentifier<'a>>,
+    // Hints of the provided arguments whose type is known at compile time,
+    // positional ones first. These are written without a type hint. Empty
+    // for statements registered before hints were elided.
+    #[serde(default)]
+    pub provided_arg_hints: Vec<Option<TypeHint>>,
 }
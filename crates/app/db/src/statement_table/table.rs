@@ -1,7 +1,7 @@
 use rusqlite::{ToSql, params, types::FromSql};
 use serde::{Deserialize, Serialize};
 
-use crate::*;
+use crate::{gc::IdReuse, *};
 
 pub trait StatementTable: private::Sealed + PartialEq + std::hash::Hash + Serialize + Deserialize<'static> {
     type Id: ToSql + FromSql;
@@ -31,8 +31,18 @@ impl<T: StatementTable> Table<T> for DbClient<CrateDb> {
         prepared_statement.query_row([id], |res| res.get(0)).optional_json()
     }
 
+    fn find_all(&self) -> Result<Vec<(<T as Record>::Id, T)>, DbClientError> {
+        let mut prepared_statement = self
+            .connection
+            .prepare(&format!("SELECT id, json(statement) FROM {} ORDER BY id", T::NAME))?;
+
+        prepared_statement
+            .query_map([], |res| Ok((res.get(0)?, res.get(1)?)))?
+            .list_json()
+    }
+
     fn insert(&self, statement: &T) -> Result<<T as Record>::Id, DbClientError> {
-        let hash = Hash::new(statement);
+        let hash = Hash::new(statement)?;
 
         let current_write_registers = self.find_statement_by_hash::<T>(hash)?;
 
@@ -44,6 +54,21 @@ impl<T: StatementTable> Table<T> for DbClient<CrateDb> {
 
         insert_unchecked::<T>(self, hash, statement)
     }
+
+    fn insert_with_id(&self, id: <T as Record>::Id, statement: &T) -> Result<(), DbClientError> {
+        let hash = Hash::new(statement)?;
+        let json_statement = serde_json::to_value(statement)?;
+
+        self.connection.execute(
+            &format!(
+                "INSERT INTO {} (id, hash, statement) VALUES (?1, ?2, jsonb(?3))",
+                T::NAME
+            ),
+            params![id, hash, json_statement],
+        )?;
+
+        Ok(())
+    }
 }
 
 // Separate trait to avoid exposing `Hash` in public API
@@ -86,12 +111,18 @@ pub(crate) fn insert_unchecked<T: StatementTable>(
 ) -> Result<T::Id, DbClientError> {
     let json_statement = serde_json::to_value(statement)?;
 
-    let mut prepared_statement = db.connection.prepare(&format!(
-        "INSERT INTO {} (hash, statement) VALUES (?1, jsonb(?2)) RETURNING id",
-        T::NAME,
-    ))?;
-
-    prepared_statement
-        .query_row(params![hash, json_statement], |res| res.get(0))
-        .map_err(Into::into)
+    immediate_transaction(&db.connection, |connection| {
+        let id = next_short_id(connection, T::NAME, IdReuse::load(connection)?)?;
+
+        connection
+            .query_row(
+                &format!(
+                    "INSERT INTO {} (id, hash, statement) VALUES (?1, ?2, jsonb(?3)) RETURNING id",
+                    T::NAME,
+                ),
+                params![id, hash, json_statement],
+                |res| res.get(0),
+            )
+            .map_err(Into::into)
+    })
 }
@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
-use redefmt_core::identifiers::TypeStructureId;
+use redefmt_args::processor::ProcessedFormatString;
+use redefmt_core::{frame::BuiltinTypeStructure, identifiers::TypeStructureId};
 
 use crate::*;
 
@@ -13,7 +14,66 @@ impl<'a> StatementTable for TypeStructure<'a> {
 #[derive(Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct TypeStructure<'a> {
     pub name: Cow<'a, str>,
+    /// Module path of the type definition, ex. `my_crate::module`
+    #[serde(default)]
+    pub module_path: Cow<'a, str>,
+    /// Type parameter names, ex. `["T"]`, empty unless derived with `redefmt(type_names)`
+    ///
+    /// The `core::any::type_name` of each parameter is dispatched right after
+    /// the type structure ID, in declaration order.
+    #[serde(default)]
+    pub generics: Vec<String>,
     pub variant: TypeStructureVariant,
+    /// Format string used when printed with `{}`, see `DeferredTypeValue::display`
+    #[serde(borrow, default)]
+    pub display: Option<ProcessedFormatString<'a>>,
+}
+
+impl TypeStructure<'static> {
+    /// Type structure of a core library type, never stored in any crate database
+    pub fn builtin(builtin: BuiltinTypeStructure) -> Self {
+        let (module_path, name, variants) = match builtin {
+            BuiltinTypeStructure::Option => ("core::option", "Option", [("None", 0), ("Some", 1)].as_slice()),
+            BuiltinTypeStructure::Result => ("core::result", "Result", [("Ok", 1), ("Err", 1)].as_slice()),
+            BuiltinTypeStructure::Ordering => (
+                "core::cmp",
+                "Ordering",
+                [("Less", 0), ("Equal", 0), ("Greater", 0)].as_slice(),
+            ),
+            BuiltinTypeStructure::Bound => (
+                "core::ops::range",
+                "Bound",
+                [("Included", 1), ("Excluded", 1), ("Unbounded", 0)].as_slice(),
+            ),
+            BuiltinTypeStructure::Poll => ("core::task::poll", "Poll", [("Ready", 1), ("Pending", 0)].as_slice()),
+            BuiltinTypeStructure::ControlFlow => (
+                "core::ops::control_flow",
+                "ControlFlow",
+                [("Continue", 1), ("Break", 1)].as_slice(),
+            ),
+        };
+
+        let variants = variants
+            .iter()
+            .map(|(variant_name, field_count)| {
+                let struct_variant = match field_count {
+                    0 => StructVariant::Unit,
+                    field_count => StructVariant::Tuple(*field_count),
+                };
+
+                (variant_name.to_string(), struct_variant)
+            })
+            .collect();
+
+        // Generic arguments aren't dispatched by the builtin `Format` implementations
+        Self {
+            name: name.into(),
+            module_path: module_path.into(),
+            generics: Vec::new(),
+            variant: TypeStructureVariant::Enum(variants),
+            display: None,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
@@ -51,10 +111,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn builtin() {
+        let type_structure = TypeStructure::builtin(BuiltinTypeStructure::Option);
+
+        let expected_variants = vec![
+            ("None".to_string(), StructVariant::Unit),
+            ("Some".to_string(), StructVariant::Tuple(1)),
+        ];
+
+        assert_eq!("Option", type_structure.name);
+        assert_eq!("core::option", type_structure.module_path);
+        assert_eq!(TypeStructureVariant::Enum(expected_variants), type_structure.variant);
+    }
+
     fn mock_type_structure_expression(name: &str) -> TypeStructure<'_> {
         TypeStructure {
             name: name.into(),
+            module_path: "x".into(),
+            generics: Vec::new(),
             variant: TypeStructureVariant::Struct(StructVariant::Unit),
+            display: None,
         }
     }
 }
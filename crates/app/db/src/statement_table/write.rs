@@ -39,6 +39,7 @@ mod tests {
             append_newline: false,
             expected_positional_arg_count: 0,
             expected_named_args: Default::default(),
+            provided_arg_hints: Default::default(),
         }
     }
 }
@@ -7,5 +7,13 @@ pub trait Record {
 pub trait Table<R: Record> {
     fn find_by_id(&self, id: R::Id) -> Result<Option<R>, DbClientError>;
 
+    /// All records, ordered by ID
+    fn find_all(&self) -> Result<Vec<(R::Id, R)>, DbClientError>;
+
     fn insert(&self, record: &R) -> Result<R::Id, DbClientError>;
+
+    /// Insert a record under an ID assigned elsewhere, e.g. by another state directory
+    ///
+    /// Fails if the ID is already taken.
+    fn insert_with_id(&self, id: R::Id, record: &R) -> Result<(), DbClientError>;
 }
@@ -0,0 +1,19 @@
+use elsa::sync::FrozenMap;
+use redefmt_core::frame::BuiltinTypeStructure;
+use redefmt_db::statement_table::type_structure::TypeStructure;
+
+#[derive(Default)]
+pub struct BuiltinTypeStructureCache {
+    map: FrozenMap<u8, Box<TypeStructure<'static>>>,
+}
+
+impl BuiltinTypeStructureCache {
+    pub fn get_or_insert(&self, builtin: BuiltinTypeStructure) -> &TypeStructure<'static> {
+        let repr = builtin as u8;
+
+        match self.map.get(&repr) {
+            Some(type_structure) => type_structure,
+            None => self.map.insert(repr, Box::new(TypeStructure::builtin(builtin))),
+        }
+    }
+}
@@ -8,4 +8,5 @@ pub struct RedefmtDecoderCache {
     pub(crate) print_statement: StatementCache<PrintStatement<'static>>,
     pub(crate) write_statement: StatementCache<WriteStatement<'static>>,
     pub(crate) type_structure: StatementCache<TypeStructure<'static>>,
+    pub(crate) builtin_type_structure: BuiltinTypeStructureCache,
 }
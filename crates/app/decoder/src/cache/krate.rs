@@ -25,16 +25,16 @@ impl CrateCache {
         &self,
         id: CrateId,
         db: &DbClient<MainDb>,
-        state_dir: &Path,
+        state_dir: Option<&Path>,
     ) -> Result<CrateContext<'_>, RedefmtDecoderError> {
         let (db, record) = match self.map.get(&id) {
             Some(value) => value,
             None => {
-                let Some(crate_record) = db.find_by_id(id)? else {
+                let (Some(crate_record), Some(state_dir)) = (db.find_by_id(id)?, state_dir) else {
                     return Err(RedefmtDecoderError::UnknownCrate(id));
                 };
 
-                let crate_db = DbClient::new_crate(state_dir, &crate_record.name)?;
+                let crate_db = DbClient::new_crate(state_dir, &crate_record)?;
 
                 self.map.insert(id, Box::new((crate_db, crate_record)))
             }
@@ -42,6 +42,11 @@ impl CrateCache {
 
         Ok(CrateContext { id, record, db })
     }
+
+    /// Crates already present are left as is
+    pub fn insert(&self, id: CrateId, db: DbClient<CrateDb>, record: Crate<'static>) {
+        self.map.insert(id, Box::new((db, record)));
+    }
 }
 
 #[cfg(test)]
@@ -6,3 +6,6 @@ pub(crate) use krate::{CrateCache, CrateContext};
 
 mod statement;
 pub(crate) use statement::StatementCache;
+
+mod builtin;
+pub(crate) use builtin::BuiltinTypeStructureCache;
@@ -0,0 +1,23 @@
+use redefmt_core::frame::{Header, HeaderExtension, PointerWidth};
+
+/// How integers and lengths of a frame's values were encoded by the target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValueEncoding {
+    pub pointer_width: PointerWidth,
+    pub varint: bool,
+}
+
+impl ValueEncoding {
+    /// Expects a header with its extension, if any, already merged into it
+    pub fn new(header: Header, extension: Option<HeaderExtension>) -> Self {
+        Self {
+            pointer_width: header.pointer_width(),
+            varint: extension.is_some_and(|extension| extension.contains(HeaderExtension::VARINT)),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn of_target() -> Self {
+        Self { pointer_width: PointerWidth::of_target(), varint: false }
+    }
+}
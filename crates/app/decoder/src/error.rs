@@ -2,7 +2,9 @@ use std::string::FromUtf8Error;
 
 use encode_unicode::error::Utf8Error;
 use redefmt_core::{frame::TypeHint, identifiers::CrateId};
-use redefmt_db::{DbClientError, StateDirError, crate_table::CrateName};
+use redefmt_db::{
+    DbClientError, StateDirError, bundle::BundleError, crate_table::CrateName, embedded::EmbeddedRecordError,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum RedefmtDecoderError {
@@ -12,18 +14,32 @@ pub enum RedefmtDecoderError {
     Db(#[from] DbClientError),
     #[error(transparent)]
     Io(#[from] std::io::Error),
+    #[error("failed to parse ELF file")]
+    Elf(#[from] object::Error),
+    #[error("ELF file contains no '{0}' section, was it built with the `embed` feature?")]
+    MissingEmbeddedSection(&'static str),
+    #[error("invalid embedded statements")]
+    Embedded(#[from] EmbeddedRecordError),
+    #[error("invalid statement bundle")]
+    Bundle(#[from] BundleError),
     #[error("unknown bits present in header '{0:?}'")]
     UnknownHeader(u8),
+    #[error("unknown bits present in header extension '{0:?}'")]
+    UnknownHeaderExtension(u8),
     #[error("no crate with ID '{0}' registered")]
     UnknownCrate(CrateId),
     #[error("no statement with ID '{0}' in '{1}' registered for '{1}'")]
     UnknownStatement(u16, &'static str, CrateName<'static>),
     #[error("type hint '{0}' not recognized")]
     UnknownTypeHint(u8),
+    #[error("builtin type structure '{0}' not recognized")]
+    UnknownBuiltinTypeStructure(u8),
     #[error("statement writer hint '{0}' not recognized")]
     UnknownStatementWriterHint(u8),
     #[error("invalid bytes received for '{0:?}', bytes: '{1:?}'")]
     InvalidValueBytes(TypeHint, Vec<u8>),
+    #[error("varint does not fit its {0}-bit integer type")]
+    VarintOverflow(u32),
     #[error("content length '{0}' does not fit host usize and will overflow")]
     LengthOverflow(u64),
     #[error("enum variant index '{0}' does not fit host usize and will overflow")]
@@ -36,4 +52,40 @@ pub enum RedefmtDecoderError {
     InvalidCharLength(u8),
     #[error("invalid UTF-8 character bytes")]
     InvalidUtf8Char(#[from] Utf8Error),
+    /// Frame is discarded, the decoder may continue with the next one.
+    #[error("frame CRC '{1:#06x}' does not match received CRC '{0:#06x}'")]
+    CrcMismatch(u16, u16),
+}
+
+impl RedefmtDecoderError {
+    // Errors which may be caused by lost or corrupted bytes, as opposed to
+    // failures in looking up statements. Exhaustive so that new variants must
+    // be classified explicitly.
+    pub(crate) fn is_frame_corruption(&self) -> bool {
+        match self {
+            Self::UnknownHeader(_)
+            | Self::UnknownHeaderExtension(_)
+            | Self::UnknownCrate(_)
+            | Self::UnknownStatement(..)
+            | Self::UnknownTypeHint(_)
+            | Self::UnknownBuiltinTypeStructure(_)
+            | Self::UnknownStatementWriterHint(_)
+            | Self::InvalidValueBytes(..)
+            | Self::VarintOverflow(_)
+            | Self::LengthOverflow(_)
+            | Self::VariantIndexOverflow(_)
+            | Self::UnknownVariantIndex(_)
+            | Self::InvalidStringBytes(_)
+            | Self::InvalidCharLength(_)
+            | Self::InvalidUtf8Char(_)
+            | Self::CrcMismatch(..) => true,
+            Self::StateDir(_)
+            | Self::Db(_)
+            | Self::Io(_)
+            | Self::Elf(_)
+            | Self::MissingEmbeddedSection(_)
+            | Self::Embedded(_)
+            | Self::Bundle(_) => false,
+        }
+    }
 }
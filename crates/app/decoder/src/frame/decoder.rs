@@ -1,9 +1,17 @@
+use std::path::Path;
+
 use bytes::{Buf, BytesMut};
+use object::{Object, ObjectSection};
 use redefmt_core::{
-    frame::{Header, Stamp},
+    cobs::{self, FRAME_DELIMITER},
+    frame::{Crc16, Header, HeaderExtension, Stamp},
     identifiers::{CrateId, PrintStatementId},
 };
-use redefmt_db::StateDir;
+use redefmt_db::{
+    StateDir,
+    bundle::Bundle,
+    embedded::{EMBEDDED_SECTION_NAME, EmbeddedCrate, EmbeddedRecord},
+};
 
 use crate::*;
 
@@ -12,16 +20,176 @@ pub struct RedefmtDecoder<'cache> {
     stores: Stores<'cache>,
     // reset per frame
     stage: FrameDecoderWants<'cache>,
+    crc: Option<Crc16>,
+    // `Some` if frames are COBS framed, counting the bytes dropped since the
+    // last successfully decoded frame
+    resync: Option<usize>,
 }
 
 impl<'cache> RedefmtDecoder<'cache> {
     pub fn new(cache: &'cache RedefmtDecoderCache) -> Result<Self, RedefmtDecoderError> {
         let state_dir = StateDir::resolve()?;
         let stores = Stores::new(cache, state_dir)?;
-        Ok(Self { stores, stage: FrameDecoderWants::Header })
+        Ok(Self::with_stores(stores))
+    }
+
+    /// Load statements from the `.redefmt` section of a binary built with the `embed` feature
+    ///
+    /// The state directory is not consulted, and `cache` should therefore
+    /// not be shared with decoders of other binaries.
+    pub fn from_elf(cache: &'cache RedefmtDecoderCache, elf_path: &Path) -> Result<Self, RedefmtDecoderError> {
+        let crates = Self::load_elf(elf_path)?;
+        let stores = Stores::embedded(cache, crates)?;
+
+        Ok(Self::with_stores(stores))
+    }
+
+    /// Load the crates embedded in the `.redefmt` section of a target binary
+    pub fn load_elf(elf_path: &Path) -> Result<Vec<EmbeddedCrate>, RedefmtDecoderError> {
+        let elf_bytes = std::fs::read(elf_path)?;
+        let elf_file = object::File::parse(elf_bytes.as_slice())?;
+
+        let section = elf_file
+            .section_by_name(EMBEDDED_SECTION_NAME)
+            .ok_or(RedefmtDecoderError::MissingEmbeddedSection(EMBEDDED_SECTION_NAME))?;
+
+        EmbeddedRecord::load(section.data()?).map_err(Into::into)
+    }
+
+    /// Load statements from a bundle exported with `redefmt_db::bundle::Bundle::export`
+    ///
+    /// Like [`Self::from_elf`], the state directory is not consulted.
+    pub fn from_bundle(cache: &'cache RedefmtDecoderCache, bundle_path: &Path) -> Result<Self, RedefmtDecoderError> {
+        let bundle_bytes = std::fs::read(bundle_path)?;
+
+        let crates = Bundle::load(&bundle_bytes)?;
+        let stores = Stores::embedded(cache, crates)?;
+
+        Ok(Self::with_stores(stores))
+    }
+
+    fn with_stores(stores: Stores<'cache>) -> Self {
+        Self { stores, stage: FrameDecoderWants::Header, crc: None, resync: None }
+    }
+
+    /// Expect frames dispatched by a [`CobsDispatcher`](redefmt_core::cobs::CobsDispatcher)
+    ///
+    /// Corrupted frames are skipped rather than returned as errors, the decoder
+    /// resynchronizing on the next frame delimiter instead. The number of
+    /// skipped bytes is then reported in [`RedefmtFrame::dropped_bytes`] of the
+    /// next successfully decoded frame. Database failures are still returned
+    /// as errors.
+    pub fn with_cobs_framing(mut self) -> Self {
+        self.resync = Some(0);
+        self
     }
 
     pub fn decode(&mut self, src: &mut BytesMut) -> Result<Option<RedefmtFrame<'cache>>, RedefmtDecoderError> {
+        match self.resync {
+            Some(_) => self.decode_cobs_frame(src),
+            None => self.decode_frame(src),
+        }
+    }
+
+    fn decode_cobs_frame(&mut self, src: &mut BytesMut) -> Result<Option<RedefmtFrame<'cache>>, RedefmtDecoderError> {
+        while let Some(delimiter_index) = src.iter().position(|byte| *byte == FRAME_DELIMITER) {
+            let mut encoded_frame = src.split_to(delimiter_index + 1);
+            encoded_frame.truncate(delimiter_index);
+
+            // Consecutive delimiters carry no frame content, may be used by
+            // targets to explicitly mark a frame boundary.
+            if encoded_frame.is_empty() {
+                continue;
+            }
+
+            if let Some(frame) = self.decode_cobs_frame_content(encoded_frame)? {
+                return Ok(Some(frame));
+            }
+
+            let dropped_bytes = self.resync.get_or_insert_default();
+            *dropped_bytes += delimiter_index + 1;
+        }
+
+        Ok(None)
+    }
+
+    // Returns `Ok(None)` if the frame content was corrupted
+    fn decode_cobs_frame_content(
+        &mut self,
+        mut encoded_frame: BytesMut,
+    ) -> Result<Option<RedefmtFrame<'cache>>, RedefmtDecoderError> {
+        let Ok(decoded_length) = cobs::decode_in_place(&mut encoded_frame) else {
+            return Ok(None);
+        };
+
+        encoded_frame.truncate(decoded_length);
+        let mut frame_bytes = encoded_frame;
+
+        self.stage = FrameDecoderWants::Header;
+
+        let maybe_frame = match self.decode_frame(&mut frame_bytes) {
+            Ok(maybe_frame) => maybe_frame,
+            Err(error) if error.is_frame_corruption() => None,
+            Err(error) => return Err(error),
+        };
+
+        // Truncated frames, or frames with trailing content are considered corrupt
+        let Some(mut frame) = maybe_frame.filter(|_| frame_bytes.is_empty()) else {
+            self.stage = FrameDecoderWants::Header;
+            return Ok(None);
+        };
+
+        frame.dropped_bytes = self.resync.replace(0).unwrap_or_default();
+
+        Ok(Some(frame))
+    }
+
+    // Frames with the CRC header flag are checksummed as their bytes get
+    // consumed by the stage decoders. Stages therefore decode from a borrowed
+    // view of the pending bytes, so that the consumed prefix may be checksummed
+    // before it is discarded.
+    fn decode_frame(&mut self, src: &mut BytesMut) -> Result<Option<RedefmtFrame<'cache>>, RedefmtDecoderError> {
+        let checksummed = match &self.stage {
+            FrameDecoderWants::Header => src
+                .first()
+                .is_some_and(|header_byte| Header::from_bits_retain(*header_byte).contains(Header::CRC)),
+            FrameDecoderWants::Crc(_) => false,
+            _ => self.crc.is_some(),
+        };
+
+        let (consumed_length, maybe_frame) = self.decode_borrowed_stage(src);
+
+        if checksummed && let Some(crc) = &mut self.crc {
+            crc.update(&src[..consumed_length]);
+        }
+
+        src.advance(consumed_length);
+        let maybe_frame = maybe_frame?;
+
+        // CRC trailer itself is not part of the checksum
+        match self.stage {
+            FrameDecoderWants::Crc(_) => {
+                let (consumed_length, maybe_frame) = self.decode_borrowed_stage(src);
+                src.advance(consumed_length);
+                maybe_frame
+            }
+            _ => Ok(maybe_frame),
+        }
+    }
+
+    // Returns the length of the consumed prefix of `src` along with the result
+    #[allow(clippy::type_complexity)]
+    fn decode_borrowed_stage(
+        &mut self,
+        src: &[u8],
+    ) -> (usize, Result<Option<RedefmtFrame<'cache>>, RedefmtDecoderError>) {
+        let mut pending_bytes = src;
+        let maybe_frame = self.decode_stage(&mut pending_bytes);
+
+        (src.len() - pending_bytes.len(), maybe_frame)
+    }
+
+    fn decode_stage(&mut self, src: &mut &[u8]) -> Result<Option<RedefmtFrame<'cache>>, RedefmtDecoderError> {
         let current_stage = std::mem::take(&mut self.stage);
         match current_stage {
             FrameDecoderWants::Header => {
@@ -31,25 +199,50 @@ impl<'cache> RedefmtDecoder<'cache> {
 
                 let header = Header::from_bits(header_byte).ok_or(RedefmtDecoderError::UnknownHeader(header_byte))?;
 
-                let next_stage = match header.contains(Header::STAMP) {
-                    true => FrameDecoderWants::Stamp(WantsStampStage { header }),
-                    false => FrameDecoderWants::PrintCrateId(WantsPrintCrateIdStage { header, stamp: None }),
+                self.crc = header.contains(Header::CRC).then(Crc16::new);
+
+                self.stage = match header.is_extended() {
+                    true => FrameDecoderWants::HeaderExtension(WantsHeaderExtensionStage { header }),
+                    false => WantsSequenceStage { header, encoding: ValueEncoding::new(header, None) }.into_stage(),
                 };
 
-                self.stage = next_stage;
-                self.decode(src)
+                self.decode_stage(src)
+            }
+            FrameDecoderWants::HeaderExtension(stage) => {
+                let Ok(extension_byte) = src.try_get_u8() else {
+                    self.stage = FrameDecoderWants::HeaderExtension(stage);
+                    return Ok(None);
+                };
+
+                let extension = HeaderExtension::from_bits(extension_byte)
+                    .filter(|extension| extension.contains(HeaderExtension::MARKER))
+                    .ok_or(RedefmtDecoderError::UnknownHeaderExtension(extension_byte))?;
+
+                self.stage = stage.next(extension);
+                self.decode_stage(src)
+            }
+            FrameDecoderWants::Sequence(stage) => {
+                let Ok(sequence) = src.try_get_u16() else {
+                    self.stage = FrameDecoderWants::Sequence(stage);
+                    return Ok(None);
+                };
+
+                self.stage = stage.next(Some(sequence));
+                self.decode_stage(src)
             }
             FrameDecoderWants::Stamp(stage) => {
-                let Ok(stamp) = src.try_get_u64().map(Stamp::new) else {
+                let Some(stamp) = DecoderUtils::get_unsigned(src, stage.encoding)?.map(Stamp::new) else {
                     self.stage = FrameDecoderWants::Stamp(stage);
                     return Ok(None);
                 };
 
                 self.stage = FrameDecoderWants::PrintCrateId(WantsPrintCrateIdStage {
                     header: stage.header,
+                    encoding: stage.encoding,
+                    sequence: stage.sequence,
                     stamp: Some(stamp),
                 });
-                self.decode(src)
+                self.decode_stage(src)
             }
             FrameDecoderWants::PrintCrateId(stage) => {
                 let Ok(print_crate_id) = src.try_get_u16().map(CrateId::new) else {
@@ -60,7 +253,7 @@ impl<'cache> RedefmtDecoder<'cache> {
                 let print_crate = self.stores.get_or_insert_crate(print_crate_id)?;
 
                 self.stage = stage.next(print_crate);
-                self.decode(src)
+                self.decode_stage(src)
             }
             FrameDecoderWants::PrintStatementId(stage) => {
                 let Ok(print_statement_id) = src.try_get_u16().map(PrintStatementId::new) else {
@@ -75,7 +268,7 @@ impl<'cache> RedefmtDecoder<'cache> {
                     .get_or_insert(print_statement_id, stage.print_crate)?;
 
                 self.stage = stage.next(print_statement);
-                self.decode(src)
+                self.decode_stage(src)
             }
             FrameDecoderWants::PrintStatement(mut stage) => {
                 if stage.segment_decoder.decode(&self.stores, src)?.is_none() {
@@ -85,16 +278,39 @@ impl<'cache> RedefmtDecoder<'cache> {
 
                 let item = RedefmtFrame::new(
                     stage.level,
+                    stage.sequence,
                     stage.stamp,
                     stage.crate_name,
                     stage.print_statement,
                     stage.segment_decoder.decoded_values,
                 );
 
+                if self.crc.is_some() {
+                    self.stage = FrameDecoderWants::Crc(WantsCrcStage { frame: item });
+                    return Ok(None);
+                }
+
                 self.stage = FrameDecoderWants::Header;
 
                 Ok(Some(item))
             }
+            FrameDecoderWants::Crc(stage) => {
+                let Ok(received_crc) = src.try_get_u16() else {
+                    self.stage = FrameDecoderWants::Crc(stage);
+                    return Ok(None);
+                };
+
+                // Always set for frames with the CRC header flag
+                let computed_crc = self.crc.take().unwrap_or_default().finish();
+
+                self.stage = FrameDecoderWants::Header;
+
+                if received_crc != computed_crc {
+                    return Err(RedefmtDecoderError::CrcMismatch(received_crc, computed_crc));
+                }
+
+                Ok(Some(stage.frame))
+            }
         }
     }
 }
@@ -119,7 +335,7 @@ mod mock {
         pub fn mock(cache: &'cache RedefmtDecoderCache) -> (TempDir, Self) {
             let (temp_dir, stores) = Stores::mock(cache);
 
-            let decoder = RedefmtDecoder { stores, stage: FrameDecoderWants::Header };
+            let decoder = RedefmtDecoder::with_stores(stores);
 
             (temp_dir, decoder)
         }
@@ -130,7 +346,7 @@ mod mock {
 mod tests {
     use bytes::BufMut;
     use redefmt_args::{identifier::AnyIdentifier, processed_format_string};
-    use redefmt_core::{SimpleTestDispatcher, write::WriteValue};
+    use redefmt_core::{Dispatcher, SimpleTestDispatcher, cobs::CobsDispatcher, write::WriteValue};
     use redefmt_db::{
         Table,
         crate_table::{Crate, CrateName},
@@ -190,6 +406,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn sequence() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, mut decoder) = RedefmtDecoder::mock(&cache);
+
+        let header = Header::SEQUENCE | Header::STAMP;
+        let sequence = u16::MAX;
+        let stamp = Stamp::new(123);
+
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(header.bits());
+        bytes.put_u16(sequence);
+        bytes.put_u64(*stamp.as_ref());
+
+        decoder.decode(&mut bytes).unwrap();
+
+        assert!(bytes.is_empty());
+
+        match decoder.stage {
+            FrameDecoderWants::PrintCrateId(stage) => {
+                assert_eq!(Some(sequence), stage.sequence);
+                assert_eq!(Some(stamp), stage.stamp);
+            }
+            _ => panic!("unexpected stage"),
+        }
+    }
+
+    #[test]
+    fn header_extension() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, mut decoder) = RedefmtDecoder::mock(&cache);
+
+        let header = Header::new(true, None) | Header::SEQUENCE;
+        let (extended_header, extension) = header.split_extension(HeaderExtension::VARINT);
+        let sequence = u16::MAX;
+        let stamp = Stamp::new(300);
+
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(extended_header.bits());
+        bytes.put_u8(extension.bits());
+        bytes.put_u16(sequence);
+        // varint stamp
+        bytes.put_slice(&[0xac, 0x02]);
+
+        decoder.decode(&mut bytes).unwrap();
+
+        assert!(bytes.is_empty());
+
+        match decoder.stage {
+            FrameDecoderWants::PrintCrateId(stage) => {
+                assert_eq!(header, stage.header);
+                assert!(stage.encoding.varint);
+                assert_eq!(Some(sequence), stage.sequence);
+                assert_eq!(Some(stamp), stage.stamp);
+            }
+            _ => panic!("unexpected stage"),
+        }
+    }
+
+    #[test]
+    fn unknown_header_extension_error() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, mut decoder) = RedefmtDecoder::mock(&cache);
+
+        let (extended_header, _) = Header::new(false, None).split_extension(HeaderExtension::empty());
+
+        // Missing marker bit
+        let mut bytes = BytesMut::from_iter([extended_header.bits(), HeaderExtension::VARINT.bits()]);
+
+        let error = decoder.decode(&mut bytes).unwrap_err();
+        assert!(matches!(error, RedefmtDecoderError::UnknownHeaderExtension(_)));
+    }
+
     #[test]
     fn print_crate_id() {
         let cache = RedefmtDecoderCache::default();
@@ -321,7 +610,7 @@ mod tests {
             positional: Default::default(),
             named: vec![(&arg_name, Value::Boolean(value))],
         };
-        let expected_frame = RedefmtFrame::new(None, None, &krate.name, &print_statement, decoded_values);
+        let expected_frame = RedefmtFrame::new(None, None, None, &krate.name, &print_statement, decoded_values);
 
         assert_eq!(expected_frame, actual_frame);
 
@@ -329,6 +618,201 @@ mod tests {
         assert!(matches!(decoder.stage, FrameDecoderWants::Header));
     }
 
+    #[test]
+    fn cobs_framed() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, decoder) = RedefmtDecoder::mock(&cache);
+        let mut decoder = decoder.with_cobs_framing();
+
+        let crate_id = seed_crate(&decoder);
+        decoder.stores.get_or_insert_crate(crate_id).unwrap();
+        let (print_statement_id, _, _) = seed_print_statement(&decoder, crate_id);
+
+        let mut bytes = cobs_framed_bytes(crate_id, print_statement_id, &mock_bool_content());
+
+        // Partial frames are left untouched until the delimiter is received
+        let mut partial_bytes = bytes.split_to(bytes.len() - 1);
+        assert!(decoder.decode(&mut partial_bytes).unwrap().is_none());
+        assert!(!partial_bytes.is_empty());
+
+        partial_bytes.unsplit(bytes);
+
+        let frame = decoder.decode(&mut partial_bytes).unwrap().unwrap();
+
+        assert_eq!(0, frame.dropped_bytes);
+        assert!(partial_bytes.is_empty());
+    }
+
+    #[test]
+    fn embedded_statements() {
+        let crate_id = CrateId::new(1);
+        let print_statement_id = PrintStatementId::new(2);
+        let krate = Crate::new(CrateName::new("x").unwrap());
+
+        let section = EmbeddedRecord::encode(crate_id, &krate, print_statement_id, &mock_print_statement()).unwrap();
+
+        let cache = RedefmtDecoderCache::default();
+        let stores = Stores::embedded(&cache, EmbeddedRecord::load(&section).unwrap()).unwrap();
+        let mut decoder = RedefmtDecoder::with_stores(stores);
+
+        let mut bytes = BytesMut::from_iter([Header::new(false, None).bits()]);
+        bytes.put_u16(*crate_id.as_ref());
+        bytes.put_u16(*print_statement_id.as_ref());
+        bytes.put_slice(&mock_bool_content());
+
+        let frame = decoder.decode(&mut bytes).unwrap().unwrap();
+        assert_eq!("x", frame.crate_name);
+
+        let unknown_crate_id = CrateId::new(3);
+        let mut bytes = BytesMut::from_iter([Header::new(false, None).bits()]);
+        bytes.put_u16(*unknown_crate_id.as_ref());
+
+        let result = decoder.decode(&mut bytes);
+        assert!(matches!(result, Err(RedefmtDecoderError::UnknownCrate(id)) if id == unknown_crate_id));
+    }
+
+    #[test]
+    fn bundled_statements() {
+        let cache = RedefmtDecoderCache::default();
+        let (state_dir, decoder) = RedefmtDecoder::mock(&cache);
+
+        let crate_id = seed_crate(&decoder);
+        decoder.stores.get_or_insert_crate(crate_id).unwrap();
+        let (print_statement_id, _, _) = seed_print_statement(&decoder, crate_id);
+
+        let bundle_path = state_dir.path().join("bundle");
+        std::fs::write(&bundle_path, Bundle::export(state_dir.path(), &[]).unwrap()).unwrap();
+
+        let bundle_cache = RedefmtDecoderCache::default();
+        let mut decoder = RedefmtDecoder::from_bundle(&bundle_cache, &bundle_path)
+            .unwrap()
+            .with_cobs_framing();
+
+        let mut bytes = cobs_framed_bytes(crate_id, print_statement_id, &mock_bool_content());
+        let frame = decoder.decode(&mut bytes).unwrap().unwrap();
+
+        assert_eq!("x", frame.crate_name);
+    }
+
+    #[test]
+    fn cobs_framed_resync() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, decoder) = RedefmtDecoder::mock(&cache);
+        let mut decoder = decoder.with_cobs_framing();
+
+        let crate_id = seed_crate(&decoder);
+        decoder.stores.get_or_insert_crate(crate_id).unwrap();
+        let (print_statement_id, _, _) = seed_print_statement(&decoder, crate_id);
+
+        let valid_bytes = cobs_framed_bytes(crate_id, print_statement_id, &mock_bool_content());
+
+        let mut bytes = BytesMut::new();
+        // Lost bytes at the start of the stream
+        bytes.extend_from_slice(&valid_bytes[3..]);
+        // Unknown type hint
+        bytes.extend_from_slice(&cobs_framed_bytes(crate_id, print_statement_id, &[0xFE, 1]));
+        // Truncated content
+        bytes.extend_from_slice(&cobs_framed_bytes(crate_id, print_statement_id, &[]));
+        // Trailing content
+        let mut trailing_content = mock_bool_content();
+        trailing_content.push(1);
+        bytes.extend_from_slice(&cobs_framed_bytes(crate_id, print_statement_id, &trailing_content));
+
+        let expected_dropped_bytes = bytes.len();
+
+        // Consecutive delimiters not considered dropped
+        bytes.put_u8(FRAME_DELIMITER);
+
+        bytes.extend_from_slice(&valid_bytes);
+        bytes.extend_from_slice(&valid_bytes);
+
+        let frame = decoder.decode(&mut bytes).unwrap().unwrap();
+        assert_eq!(expected_dropped_bytes, frame.dropped_bytes);
+
+        let frame = decoder.decode(&mut bytes).unwrap().unwrap();
+        assert_eq!(0, frame.dropped_bytes);
+
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn crc() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, mut decoder) = RedefmtDecoder::mock(&cache);
+
+        let crate_id = seed_crate(&decoder);
+        decoder.stores.get_or_insert_crate(crate_id).unwrap();
+        let (print_statement_id, _, _) = seed_print_statement(&decoder, crate_id);
+
+        let mut bytes = crc_trailed_bytes(crate_id, print_statement_id);
+
+        // Frame held back until trailer is received
+        let mut partial_bytes = bytes.split_to(bytes.len() - 2);
+        assert!(decoder.decode(&mut partial_bytes).unwrap().is_none());
+        assert!(matches!(decoder.stage, FrameDecoderWants::Crc(_)));
+
+        assert!(decoder.decode(&mut bytes).unwrap().is_some());
+        assert!(matches!(decoder.stage, FrameDecoderWants::Header));
+    }
+
+    #[test]
+    fn crc_mismatch_error() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, mut decoder) = RedefmtDecoder::mock(&cache);
+
+        let crate_id = seed_crate(&decoder);
+        decoder.stores.get_or_insert_crate(crate_id).unwrap();
+        let (print_statement_id, _, _) = seed_print_statement(&decoder, crate_id);
+
+        let valid_bytes = crc_trailed_bytes(crate_id, print_statement_id);
+
+        let mut bytes = valid_bytes.clone();
+        // flip boolean value bit
+        let bool_index = bytes.len() - 3;
+        bytes[bool_index] ^= 1;
+        bytes.extend_from_slice(&valid_bytes);
+
+        let error = decoder.decode(&mut bytes).unwrap_err();
+        assert!(matches!(error, RedefmtDecoderError::CrcMismatch(_, _)));
+
+        // Able to continue with the next frame
+        assert!(decoder.decode(&mut bytes).unwrap().is_some());
+        assert!(bytes.is_empty());
+    }
+
+    fn crc_trailed_bytes(crate_id: CrateId, print_statement_id: PrintStatementId) -> BytesMut {
+        let mut bytes = BytesMut::new();
+
+        bytes.put_u8((Header::new(false, None) | Header::CRC).bits());
+        bytes.put_u16(*crate_id.as_ref());
+        bytes.put_u16(*print_statement_id.as_ref());
+        bytes.extend_from_slice(&mock_bool_content());
+
+        let mut crc = Crc16::new();
+        crc.update(&bytes);
+        bytes.put_u16(crc.finish());
+
+        bytes
+    }
+
+    fn mock_bool_content() -> Vec<u8> {
+        let mut dispatcher = SimpleTestDispatcher::default();
+        true.write_value(&mut dispatcher);
+        dispatcher.bytes.to_vec()
+    }
+
+    fn cobs_framed_bytes(crate_id: CrateId, print_statement_id: PrintStatementId, content: &[u8]) -> BytesMut {
+        let mut dispatcher = CobsDispatcher::new(SimpleTestDispatcher::default());
+
+        dispatcher.write(&[Header::new(false, None).bits()]);
+        dispatcher.write(&crate_id.as_ref().to_be_bytes());
+        dispatcher.write(&print_statement_id.as_ref().to_be_bytes());
+        dispatcher.write(content);
+        dispatcher.end_frame();
+
+        dispatcher.into_inner().bytes
+    }
+
     fn seed_crate(decoder: &RedefmtDecoder) -> CrateId {
         let crate_name = CrateName::new("x").unwrap();
         let crate_record = Crate::new(crate_name);
@@ -348,11 +832,23 @@ mod tests {
     }
 
     fn mock_stamp_stage<'a>() -> FrameDecoderWants<'a> {
-        FrameDecoderWants::PrintCrateId(WantsPrintCrateIdStage { header: Header::new(false, None), stamp: None })
+        FrameDecoderWants::PrintCrateId(WantsPrintCrateIdStage {
+            header: Header::new(false, None),
+            encoding: ValueEncoding::of_target(),
+            sequence: None,
+            stamp: None,
+        })
     }
 
     fn mock_print_statement() -> PrintStatement<'static> {
-        let location = Location { file: "file.rs".into(), line: 1 };
+        let location = Location {
+            file: "file.rs".into(),
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: 10,
+            module_path: "x".into(),
+        };
 
         // NOTE: Two format arguments, but only one provided. Implicitly
         // ensures that it only needs to be encoded and decoded once
@@ -362,9 +858,10 @@ mod tests {
             append_newline: false,
             expected_positional_arg_count: 0,
             expected_named_args: vec![AnyIdentifier::parse("y").unwrap()],
+            provided_arg_hints: Vec::new(),
         };
 
-        PrintStatement { location, stored_expression }
+        PrintStatement { location, target: None, stored_expression }
     }
 
     fn put_and_decode_print_crate_id(decoder: &mut RedefmtDecoder, crate_id: CrateId) {
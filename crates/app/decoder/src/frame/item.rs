@@ -7,7 +7,7 @@ use crate::*;
 /// # Codec structure:
 ///
 /// ```txt
-/// frame := <header>[<stamp>]<print_id>(content)*
+/// frame := <header>[<sequence>][<stamp>]<print_id>(content)*[<crc>]
 /// content := <type_hint>[<length_hint>](<type_hint>{1,2})[<type_bytes>]
 /// print_id := <crate_id><print_statement_id>
 /// write_id := <crate_id><write_statement_id>
@@ -16,18 +16,31 @@ use crate::*;
 #[derive(Debug, PartialEq)]
 pub struct RedefmtFrame<'cache> {
     pub level: Option<Level>,
+    /// Wrapping frame sequence number, if enabled by the target
+    pub sequence: Option<u16>,
     pub stamp: Option<u64>,
     pub crate_name: &'cache str,
+    pub module_path: &'cache str,
+    /// Optional `target: "..."` argument of log statements
+    pub target: Option<&'cache str>,
     pub file_name: &'cache str,
     pub file_line: u32,
+    pub file_column: u32,
+    pub file_end_line: u32,
+    pub file_end_column: u32,
     pub format_string: &'cache ProcessedFormatString<'static>,
     pub append_newline: bool,
     pub decoded_values: DecodedValues<'cache>,
+    /// Bytes skipped due to corruption since the previously decoded frame
+    ///
+    /// Always zero unless the decoder resynchronizes on COBS frame delimiters.
+    pub dropped_bytes: usize,
 }
 
 impl<'cache> RedefmtFrame<'cache> {
     pub(crate) fn new(
         level: Option<Level>,
+        sequence: Option<u16>,
         stamp: Option<Stamp>,
         crate_name: &'cache CrateName<'static>,
         print_stratement: &'cache PrintStatement<'static>,
@@ -36,13 +49,20 @@ impl<'cache> RedefmtFrame<'cache> {
         // flatten to avoid exposing internal crate types
         Self {
             level,
+            sequence,
             stamp: stamp.map(|stamp| *stamp.as_ref()),
             crate_name: crate_name.as_ref(),
+            module_path: print_stratement.location.module_path.as_ref(),
+            target: print_stratement.target.as_deref(),
             file_name: print_stratement.location.file.as_ref(),
             file_line: print_stratement.location.line,
+            file_column: print_stratement.location.column,
+            file_end_line: print_stratement.location.end_line,
+            file_end_column: print_stratement.location.end_column,
             format_string: &print_stratement.stored_expression.format_string,
             append_newline: print_stratement.stored_expression.append_newline,
             decoded_values,
+            dropped_bytes: 0,
         }
     }
 }
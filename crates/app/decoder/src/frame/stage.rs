@@ -1,4 +1,4 @@
-use redefmt_core::frame::{Header, Level, Stamp};
+use redefmt_core::frame::{Header, HeaderExtension, Level, Stamp};
 use redefmt_db::{crate_table::CrateName, statement_table::print::PrintStatement};
 
 use crate::*;
@@ -11,44 +11,98 @@ use crate::*;
 pub enum FrameDecoderWants<'cache> {
     #[default]
     Header,
+    HeaderExtension(WantsHeaderExtensionStage),
+    Sequence(WantsSequenceStage),
     Stamp(WantsStampStage),
     PrintCrateId(WantsPrintCrateIdStage),
     PrintStatementId(WantsPrintStatementIdStage<'cache>),
     PrintStatement(WantsPrintStatementStage<'cache>),
+    Crc(WantsCrcStage<'cache>),
+}
+
+pub struct WantsHeaderExtensionStage {
+    pub header: Header,
+}
+
+impl WantsHeaderExtensionStage {
+    pub fn next<'cache>(self, extension: HeaderExtension) -> FrameDecoderWants<'cache> {
+        let header = self.header.merge_extension(extension);
+        let encoding = ValueEncoding::new(header, Some(extension));
+
+        WantsSequenceStage { header, encoding }.into_stage()
+    }
+}
+
+pub struct WantsSequenceStage {
+    pub header: Header,
+    pub encoding: ValueEncoding,
+}
+
+impl WantsSequenceStage {
+    pub fn into_stage<'cache>(self) -> FrameDecoderWants<'cache> {
+        match self.header.contains(Header::SEQUENCE) {
+            true => FrameDecoderWants::Sequence(self),
+            false => self.next(None),
+        }
+    }
+
+    pub fn next<'cache>(self, sequence: Option<u16>) -> FrameDecoderWants<'cache> {
+        let Self { header, encoding } = self;
+
+        match header.contains(Header::STAMP) {
+            true => FrameDecoderWants::Stamp(WantsStampStage { header, encoding, sequence }),
+            false => {
+                FrameDecoderWants::PrintCrateId(WantsPrintCrateIdStage { header, encoding, sequence, stamp: None })
+            }
+        }
+    }
 }
 
 pub struct WantsStampStage {
     pub header: Header,
+    pub encoding: ValueEncoding,
+    pub sequence: Option<u16>,
 }
 
 pub struct WantsPrintCrateIdStage {
     pub header: Header,
+    pub encoding: ValueEncoding,
+    pub sequence: Option<u16>,
     pub stamp: Option<Stamp>,
 }
 
 impl WantsPrintCrateIdStage {
     pub fn next<'cache>(self, print_crate: CrateContext<'cache>) -> FrameDecoderWants<'cache> {
-        let Self { header, stamp } = self;
-        FrameDecoderWants::PrintStatementId(WantsPrintStatementIdStage { header, stamp, print_crate })
+        let Self { header, encoding, sequence, stamp } = self;
+        FrameDecoderWants::PrintStatementId(WantsPrintStatementIdStage {
+            header,
+            encoding,
+            sequence,
+            stamp,
+            print_crate,
+        })
     }
 }
 
 pub struct WantsPrintStatementIdStage<'cache> {
     pub header: Header,
+    pub encoding: ValueEncoding,
+    pub sequence: Option<u16>,
     pub stamp: Option<Stamp>,
     pub print_crate: CrateContext<'cache>,
 }
 
 impl<'cache> WantsPrintStatementIdStage<'cache> {
     pub fn next(self, print_statement: &'cache PrintStatement<'static>) -> FrameDecoderWants<'cache> {
-        let Self { header, stamp, print_crate } = self;
+        let Self { header, encoding, sequence, stamp, print_crate } = self;
 
         let level = header.level();
         let crate_name = &print_crate.record.name;
-        let segment_decoder = SegmentsDecoder::new(header.pointer_width(), &print_statement.stored_expression);
+        let segment_decoder = SegmentsDecoder::new(encoding, &print_statement.stored_expression);
 
         FrameDecoderWants::PrintStatement(WantsPrintStatementStage {
             level,
+            sequence,
             stamp,
             crate_name,
             print_statement,
@@ -59,8 +113,13 @@ impl<'cache> WantsPrintStatementIdStage<'cache> {
 
 pub struct WantsPrintStatementStage<'cache> {
     pub level: Option<Level>,
+    pub sequence: Option<u16>,
     pub stamp: Option<Stamp>,
     pub crate_name: &'cache CrateName<'static>,
     pub print_statement: &'cache PrintStatement<'static>,
     pub segment_decoder: SegmentsDecoder<'cache>,
 }
+
+pub struct WantsCrcStage<'cache> {
+    pub frame: RedefmtFrame<'cache>,
+}
@@ -25,5 +25,8 @@ pub use frame::{RedefmtDecoder, RedefmtFrame};
 mod sub_decoders;
 pub(crate) use sub_decoders::*;
 
+mod encoding;
+pub(crate) use encoding::ValueEncoding;
+
 mod utils;
 pub(crate) use utils::DecoderUtils;
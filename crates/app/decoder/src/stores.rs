@@ -1,12 +1,13 @@
 use std::path::PathBuf;
 
 use redefmt_core::identifiers::CrateId;
-use redefmt_db::{DbClient, MainDb};
+use redefmt_db::{DbClient, MainDb, embedded::EmbeddedCrate};
 
 use crate::*;
 
 pub struct Stores<'cache> {
-    state_dir: PathBuf,
+    // `None` if all crates were embedded in the target binary
+    state_dir: Option<PathBuf>,
     pub(crate) main_db: DbClient<MainDb>,
     pub(crate) cache: &'cache RedefmtDecoderCache,
 }
@@ -15,11 +16,27 @@ impl<'cache> Stores<'cache> {
     pub fn new(cache: &'cache RedefmtDecoderCache, state_dir: PathBuf) -> Result<Self, RedefmtDecoderError> {
         let main_db = DbClient::new_main(&state_dir)?;
 
-        Ok(Self { state_dir, main_db, cache })
+        Ok(Self { state_dir: Some(state_dir), main_db, cache })
+    }
+
+    /// Seeds the crate cache with the given crates, the state directory is never consulted
+    pub fn embedded(
+        cache: &'cache RedefmtDecoderCache,
+        crates: Vec<EmbeddedCrate>,
+    ) -> Result<Self, RedefmtDecoderError> {
+        let main_db = DbClient::new_in_memory()?;
+
+        for EmbeddedCrate { id, record, db } in crates {
+            cache.krate.insert(id, db, record);
+        }
+
+        Ok(Self { state_dir: None, main_db, cache })
     }
 
     pub fn get_or_insert_crate(&self, crate_id: CrateId) -> Result<CrateContext<'cache>, RedefmtDecoderError> {
-        self.cache.krate.get_or_insert(crate_id, &self.main_db, &self.state_dir)
+        self.cache
+            .krate
+            .get_or_insert(crate_id, &self.main_db, self.state_dir.as_deref())
     }
 }
 
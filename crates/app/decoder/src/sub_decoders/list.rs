@@ -1,10 +1,10 @@
-use bytes::BytesMut;
-use redefmt_core::frame::{PointerWidth, TypeHint};
+use bytes::Buf;
+use redefmt_core::frame::TypeHint;
 
 use crate::*;
 
 pub struct ListValueDecoder<'cache> {
-    pointer_width: PointerWidth,
+    encoding: ValueEncoding,
     expected_length: usize,
     buffer: Vec<Value<'cache>>,
     element_context: Option<Box<ValueDecoder<'cache>>>,
@@ -12,9 +12,9 @@ pub struct ListValueDecoder<'cache> {
 }
 
 impl<'cache> ListValueDecoder<'cache> {
-    pub fn new(pointer_width: PointerWidth, expected_length: usize) -> Self {
+    pub fn new(encoding: ValueEncoding, expected_length: usize) -> Self {
         Self {
-            pointer_width,
+            encoding,
             expected_length,
             buffer: Vec::with_capacity(expected_length),
             element_context: Default::default(),
@@ -25,8 +25,13 @@ impl<'cache> ListValueDecoder<'cache> {
     pub fn decode_list(
         &mut self,
         stores: &Stores<'cache>,
-        src: &mut BytesMut,
+        src: &mut impl Buf,
     ) -> Result<Option<Vec<Value<'cache>>>, RedefmtDecoderError> {
+        // No element type hint is written for empty lists
+        if self.expected_length == 0 {
+            return Ok(Some(Vec::new()));
+        }
+
         let Some(element_type_hint) = self.get_or_insert_element_type_hint(src)? else {
             return Ok(None);
         };
@@ -34,7 +39,7 @@ impl<'cache> ListValueDecoder<'cache> {
         while self.buffer.len() < self.expected_length {
             let element_context = self
                 .element_context
-                .get_or_insert_with(|| Box::new(ValueDecoder::new(self.pointer_width, element_type_hint)));
+                .get_or_insert_with(|| Box::new(ValueDecoder::new(self.encoding, element_type_hint)));
 
             match element_context.decode(stores, src)? {
                 Some(value) => {
@@ -53,7 +58,7 @@ impl<'cache> ListValueDecoder<'cache> {
     pub fn decode_dyn_list(
         &mut self,
         stores: &Stores<'cache>,
-        src: &mut BytesMut,
+        src: &mut impl Buf,
     ) -> Result<Option<Vec<Value<'cache>>>, RedefmtDecoderError> {
         while self.buffer.len() < self.expected_length {
             let Some(element_type_hint) = self.get_or_insert_element_type_hint(src)? else {
@@ -62,7 +67,7 @@ impl<'cache> ListValueDecoder<'cache> {
 
             let element_context = self
                 .element_context
-                .get_or_insert_with(|| Box::new(ValueDecoder::new(self.pointer_width, element_type_hint)));
+                .get_or_insert_with(|| Box::new(ValueDecoder::new(self.encoding, element_type_hint)));
 
             match element_context.decode(stores, src)? {
                 Some(value) => {
@@ -81,7 +86,7 @@ impl<'cache> ListValueDecoder<'cache> {
         Ok(Some(values))
     }
 
-    fn get_or_insert_element_type_hint(&mut self, src: &mut BytesMut) -> Result<Option<TypeHint>, RedefmtDecoderError> {
+    fn get_or_insert_element_type_hint(&mut self, src: &mut impl Buf) -> Result<Option<TypeHint>, RedefmtDecoderError> {
         if let Some(type_hint) = self.element_type_hint {
             return Ok(Some(type_hint));
         }
@@ -1,5 +1,5 @@
-use bytes::BytesMut;
-use redefmt_core::frame::{PointerWidth, TypeHint};
+use bytes::Buf;
+use redefmt_core::frame::TypeHint;
 use redefmt_db::statement_table::stored_format_expression::StoredFormatExpression;
 
 use crate::*;
@@ -10,19 +10,19 @@ struct SegmentValueContext<'cache> {
 }
 
 pub struct SegmentsDecoder<'cache> {
-    pointer_width: PointerWidth,
+    encoding: ValueEncoding,
     current_value: Option<SegmentValueContext<'cache>>,
     pub(crate) stored_expression: &'cache StoredFormatExpression<'static>,
     pub(crate) decoded_values: DecodedValues<'cache>,
 }
 
 impl<'cache> SegmentsDecoder<'cache> {
-    pub fn new(pointer_width: PointerWidth, stored_expression: &'cache StoredFormatExpression<'static>) -> Self {
+    pub fn new(encoding: ValueEncoding, stored_expression: &'cache StoredFormatExpression<'static>) -> Self {
         let decoded_values = DecodedValues::new_with_capacity(stored_expression);
-        Self { stored_expression, pointer_width, current_value: None, decoded_values }
+        Self { stored_expression, encoding, current_value: None, decoded_values }
     }
 
-    pub fn decode(&mut self, stores: &Stores<'cache>, src: &mut BytesMut) -> Result<Option<()>, RedefmtDecoderError> {
+    pub fn decode(&mut self, stores: &Stores<'cache>, src: &mut impl Buf) -> Result<Option<()>, RedefmtDecoderError> {
         if let Some(current_value_context) = self.current_value.take() {
             let SegmentValueContext { type_hint, mut value_decoder } = current_value_context;
 
@@ -39,11 +39,22 @@ impl<'cache> SegmentsDecoder<'cache> {
         }
 
         while !self.decoded_values.is_filled(self.stored_expression) {
-            let Some(type_hint) = DecoderUtils::get_type_hint(src)? else {
-                return Ok(None);
+            let stored_type_hint = self
+                .stored_expression
+                .provided_arg_hints
+                .get(self.decoded_values.len())
+                .copied()
+                .flatten();
+
+            let type_hint = match stored_type_hint {
+                Some(type_hint) => type_hint,
+                None => match DecoderUtils::get_type_hint(src)? {
+                    Some(type_hint) => type_hint,
+                    None => return Ok(None),
+                },
             };
 
-            let mut value_decoder = ValueDecoder::new(self.pointer_width, type_hint);
+            let mut value_decoder = ValueDecoder::new(self.encoding, type_hint);
 
             match value_decoder.decode(stores, src)? {
                 Some(value) => {
@@ -59,3 +70,73 @@ impl<'cache> SegmentsDecoder<'cache> {
         Ok(Some(()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use redefmt_args::{identifier::AnyIdentifier, processed_format_string};
+    use redefmt_core::{SimpleTestDispatcher, write::WriteValue};
+
+    use super::*;
+
+    #[test]
+    fn stored_type_hints() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, stores) = Stores::mock(&cache);
+
+        let stored_expression = StoredFormatExpression {
+            format_string: processed_format_string!("{} {}", 300u16, true),
+            append_newline: false,
+            expected_positional_arg_count: 2,
+            expected_named_args: Vec::new(),
+            provided_arg_hints: vec![Some(TypeHint::U16), None],
+        };
+
+        let mut dispatcher = SimpleTestDispatcher::default();
+        300u16.write_raw(&mut dispatcher);
+        true.write_value(&mut dispatcher);
+
+        let mut bytes = dispatcher.bytes;
+        let mut segments_decoder = SegmentsDecoder::new(ValueEncoding::of_target(), &stored_expression);
+
+        assert!(segments_decoder.decode(&stores, &mut bytes).unwrap().is_some());
+        assert!(bytes.is_empty());
+
+        let expected_values = vec![Value::U16(300), Value::Boolean(true)];
+        assert_eq!(expected_values, segments_decoder.decoded_values.positional);
+    }
+
+    #[test]
+    fn stored_type_hints_named_and_captured() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, stores) = Stores::mock(&cache);
+
+        // `"{x} {} {named} {y}", true, named = 2u16` with `x` and `y` captured
+        let expected_named_args = ["named", "x", "y"].map(|name| AnyIdentifier::parse(name).unwrap());
+
+        let stored_expression = StoredFormatExpression {
+            format_string: processed_format_string!("{x} {} {named} {y}", true, named = 2u16),
+            append_newline: false,
+            expected_positional_arg_count: 1,
+            expected_named_args: expected_named_args.to_vec(),
+            provided_arg_hints: vec![Some(TypeHint::Boolean), Some(TypeHint::U16), None, None],
+        };
+
+        let mut dispatcher = SimpleTestDispatcher::default();
+        true.write_raw(&mut dispatcher);
+        2u16.write_raw(&mut dispatcher);
+        'x'.write_value(&mut dispatcher);
+        3u8.write_value(&mut dispatcher);
+
+        let mut bytes = dispatcher.bytes;
+        let mut segments_decoder = SegmentsDecoder::new(ValueEncoding::of_target(), &stored_expression);
+
+        assert!(segments_decoder.decode(&stores, &mut bytes).unwrap().is_some());
+        assert!(bytes.is_empty());
+
+        assert_eq!(vec![Value::Boolean(true)], segments_decoder.decoded_values.positional);
+
+        let [named, x, y] = &expected_named_args;
+        let expected_named_values = vec![(named, Value::U16(2)), (x, Value::Char('x')), (y, Value::U8(3))];
+        assert_eq!(expected_named_values, segments_decoder.decoded_values.named);
+    }
+}
@@ -1,5 +1,5 @@
-use bytes::{Buf, BytesMut};
-use redefmt_core::{frame::PointerWidth, identifiers::TypeStructureId};
+use bytes::Buf;
+use redefmt_core::{frame::TypeHint, identifiers::TypeStructureId};
 use redefmt_db::statement_table::type_structure::{StructVariant, TypeStructure, TypeStructureVariant};
 
 use crate::*;
@@ -10,32 +10,47 @@ enum TypeStructureValueSubDecoder<'cache> {
 }
 
 enum TypeStructureDecoderWants<'cache> {
-    SubDecoder,
+    Id(CrateContext<'cache>),
+    GenericArgs(&'cache TypeStructure<'static>),
+    SubDecoder(&'cache TypeStructure<'static>),
     Value(&'cache TypeStructure<'static>, TypeStructureValueSubDecoder<'cache>),
 }
 
 pub struct TypeStructureDecoder<'cache> {
-    pointer_width: PointerWidth,
-    crate_context: CrateContext<'cache>,
+    encoding: ValueEncoding,
     decoder_stage: TypeStructureDecoderWants<'cache>,
+    generic_args: Vec<String>,
+    // boxed given that value decoders may themselves contain type structure decoders
+    generic_arg_decoder: Option<Box<ValueDecoder<'cache>>>,
 }
 
 impl<'cache> TypeStructureDecoder<'cache> {
-    pub fn new(pointer_width: PointerWidth, crate_context: CrateContext<'cache>) -> Self {
+    pub fn new(encoding: ValueEncoding, crate_context: CrateContext<'cache>) -> Self {
         Self {
-            pointer_width,
-            crate_context,
-            decoder_stage: TypeStructureDecoderWants::SubDecoder,
+            encoding,
+            decoder_stage: TypeStructureDecoderWants::Id(crate_context),
+            generic_args: Vec::new(),
+            generic_arg_decoder: None,
+        }
+    }
+
+    /// Decoder for an already resolved type structure, such as the builtin ones
+    pub fn resolved(encoding: ValueEncoding, type_structure: &'cache TypeStructure<'static>) -> Self {
+        Self {
+            encoding,
+            decoder_stage: TypeStructureDecoderWants::GenericArgs(type_structure),
+            generic_args: Vec::new(),
+            generic_arg_decoder: None,
         }
     }
 
     pub fn decode(
         &mut self,
         stores: &Stores<'cache>,
-        src: &mut BytesMut,
+        src: &mut impl Buf,
     ) -> Result<Option<TypeStructureValue<'cache>>, RedefmtDecoderError> {
         match &mut self.decoder_stage {
-            TypeStructureDecoderWants::SubDecoder => {
+            TypeStructureDecoderWants::Id(crate_context) => {
                 let Ok(type_structure_id) = src.try_get_u16().map(TypeStructureId::new) else {
                     return Ok(None);
                 };
@@ -43,21 +58,56 @@ impl<'cache> TypeStructureDecoder<'cache> {
                 let type_structure = stores
                     .cache
                     .type_structure
-                    .get_or_insert(type_structure_id, self.crate_context)?;
+                    .get_or_insert(type_structure_id, *crate_context)?;
+
+                self.decoder_stage = TypeStructureDecoderWants::GenericArgs(type_structure);
+
+                self.decode(stores, src)
+            }
+            TypeStructureDecoderWants::GenericArgs(type_structure) => {
+                let type_structure = *type_structure;
+
+                while self.generic_args.len() < type_structure.generics.len() {
+                    let generic_arg_decoder = self
+                        .generic_arg_decoder
+                        .get_or_insert_with(|| Box::new(ValueDecoder::new(self.encoding, TypeHint::StringSlice)));
+
+                    let Some(generic_arg_value) = generic_arg_decoder.decode(stores, src)? else {
+                        return Ok(None);
+                    };
+
+                    self.generic_arg_decoder = None;
+
+                    let Value::String(generic_arg) = generic_arg_value else {
+                        unreachable!("string slice value decoder returned a non-string value");
+                    };
+
+                    self.generic_args.push(generic_arg);
+                }
+
+                self.decoder_stage = TypeStructureDecoderWants::SubDecoder(type_structure);
+
+                self.decode(stores, src)
+            }
+            TypeStructureDecoderWants::SubDecoder(type_structure) => {
+                let type_structure = *type_structure;
 
                 let sub_decoder = match &type_structure.variant {
                     TypeStructureVariant::Struct(struct_variant) => match struct_variant {
                         StructVariant::Unit => {
                             return Ok(Some(TypeStructureValue {
                                 name: &type_structure.name,
+                                module_path: &type_structure.module_path,
+                                generic_args: std::mem::take(&mut self.generic_args),
                                 variant: TypeStructureVariantValue::Struct(StructVariantValue::Unit),
+                                display: type_structure.display.as_ref(),
                             }));
                         }
-                        StructVariant::Tuple(tuple_length) => TypeStructureValueSubDecoder::Struct(
-                            StructDecoder::tuple(self.pointer_width, *tuple_length),
-                        ),
+                        StructVariant::Tuple(tuple_length) => {
+                            TypeStructureValueSubDecoder::Struct(StructDecoder::tuple(self.encoding, *tuple_length))
+                        }
                         StructVariant::Named(field_names) => {
-                            TypeStructureValueSubDecoder::Struct(StructDecoder::named(self.pointer_width, field_names))
+                            TypeStructureValueSubDecoder::Struct(StructDecoder::named(self.encoding, field_names))
                         }
                     },
                     TypeStructureVariant::Enum(variants) => {
@@ -75,12 +125,17 @@ impl<'cache> TypeStructureDecoder<'cache> {
                         .decode(stores, src)?
                         .map(TypeStructureVariantValue::Struct),
                     TypeStructureValueSubDecoder::Enum(enum_decoder) => enum_decoder
-                        .decode(self.pointer_width, stores, src)?
+                        .decode(self.encoding, stores, src)?
                         .map(TypeStructureVariantValue::Enum),
                 };
 
-                let maybe_value =
-                    decoded_variant.map(|variant| TypeStructureValue { name: &type_structure.name, variant });
+                let maybe_value = decoded_variant.map(|variant| TypeStructureValue {
+                    name: &type_structure.name,
+                    module_path: &type_structure.module_path,
+                    generic_args: std::mem::take(&mut self.generic_args),
+                    variant,
+                    display: type_structure.display.as_ref(),
+                });
 
                 Ok(maybe_value)
             }
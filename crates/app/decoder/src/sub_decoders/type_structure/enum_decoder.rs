@@ -1,5 +1,4 @@
-use bytes::BytesMut;
-use redefmt_core::frame::PointerWidth;
+use bytes::Buf;
 use redefmt_db::statement_table::type_structure::StructVariant;
 
 use crate::*;
@@ -17,13 +16,13 @@ pub enum EnumDecoder<'cache> {
 impl<'cache> EnumDecoder<'cache> {
     pub fn decode(
         &mut self,
-        pointer_width: PointerWidth,
+        encoding: ValueEncoding,
         stores: &Stores<'cache>,
-        src: &mut BytesMut,
+        src: &mut impl Buf,
     ) -> Result<Option<(&'cache str, StructVariantValue<'cache>)>, RedefmtDecoderError> {
         match self {
             EnumDecoder::WantsIndex { variants } => {
-                let Some(target_length) = DecoderUtils::get_target_usize(src, pointer_width) else {
+                let Some(target_length) = DecoderUtils::get_target_usize(src, encoding)? else {
                     return Ok(None);
                 };
 
@@ -39,11 +38,11 @@ impl<'cache> EnumDecoder<'cache> {
                     StructVariant::Unit => {
                         return Ok(Some((variant_name, StructVariantValue::Unit)));
                     }
-                    StructVariant::Tuple(tuple_length) => StructDecoder::tuple(pointer_width, *tuple_length),
-                    StructVariant::Named(field_names) => StructDecoder::named(pointer_width, field_names),
+                    StructVariant::Tuple(tuple_length) => StructDecoder::tuple(encoding, *tuple_length),
+                    StructVariant::Named(field_names) => StructDecoder::named(encoding, field_names),
                 };
 
-                EnumDecoder::WantsStructValue { variant_name, struct_decoder }.decode(pointer_width, stores, src)
+                EnumDecoder::WantsStructValue { variant_name, struct_decoder }.decode(encoding, stores, src)
             }
             EnumDecoder::WantsStructValue { variant_name, struct_decoder } => struct_decoder
                 .decode(stores, src)
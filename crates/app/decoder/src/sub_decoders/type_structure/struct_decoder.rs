@@ -1,5 +1,4 @@
-use bytes::BytesMut;
-use redefmt_core::frame::PointerWidth;
+use bytes::Buf;
 
 use crate::*;
 
@@ -14,14 +13,14 @@ pub enum StructDecoder<'cache> {
 }
 
 impl<'cache> StructDecoder<'cache> {
-    pub fn tuple(pointer_width: PointerWidth, tuple_length: u8) -> Self {
+    pub fn tuple(encoding: ValueEncoding, tuple_length: u8) -> Self {
         let tuple_length = tuple_length as usize;
-        let list_decoder = ListValueDecoder::new(pointer_width, tuple_length);
+        let list_decoder = ListValueDecoder::new(encoding, tuple_length);
         Self::TupleStruct { list_decoder }
     }
 
-    pub fn named(pointer_width: PointerWidth, field_names: &'cache Vec<String>) -> Self {
-        let list_decoder = ListValueDecoder::new(pointer_width, field_names.len());
+    pub fn named(encoding: ValueEncoding, field_names: &'cache Vec<String>) -> Self {
+        let list_decoder = ListValueDecoder::new(encoding, field_names.len());
         Self::NamedStruct { field_names, list_decoder }
     }
 }
@@ -30,7 +29,7 @@ impl<'cache> StructDecoder<'cache> {
     pub fn decode(
         &mut self,
         stores: &Stores<'cache>,
-        src: &mut BytesMut,
+        src: &mut impl Buf,
     ) -> Result<Option<StructVariantValue<'cache>>, RedefmtDecoderError> {
         let struct_variant_value = match self {
             StructDecoder::TupleStruct { list_decoder } => {
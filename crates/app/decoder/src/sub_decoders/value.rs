@@ -1,14 +1,19 @@
-use bytes::{Buf, BufMut, BytesMut};
+use std::{
+    net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6},
+    time::Duration,
+};
+
+use bytes::{Buf, BufMut};
 use encode_unicode::CharExt;
 use redefmt_core::{
-    frame::{PointerWidth, TypeHint},
+    frame::{BuiltinTypeStructure, TypeHint},
     identifiers::CrateId,
 };
 
 use crate::*;
 
 pub struct ValueDecoder<'cache> {
-    pointer_width: PointerWidth,
+    encoding: ValueEncoding,
     type_hint: TypeHint,
     length_context: Option<usize>,
     list_decoder: Option<ListValueDecoder<'cache>>,
@@ -17,9 +22,9 @@ pub struct ValueDecoder<'cache> {
 }
 
 impl<'cache> ValueDecoder<'cache> {
-    pub fn new(pointer_width: PointerWidth, type_hint: TypeHint) -> Self {
+    pub fn new(encoding: ValueEncoding, type_hint: TypeHint) -> Self {
         Self {
-            pointer_width,
+            encoding,
             type_hint,
             length_context: None,
             list_decoder: None,
@@ -31,23 +36,25 @@ impl<'cache> ValueDecoder<'cache> {
     pub fn decode(
         &mut self,
         stores: &Stores<'cache>,
-        src: &mut BytesMut,
+        src: &mut impl Buf,
     ) -> Result<Option<Value<'cache>>, RedefmtDecoderError> {
         let maybe_simple_value = match self.type_hint {
             TypeHint::U8 => src.try_get_u8().ok().map(Value::U8),
-            TypeHint::U16 => src.try_get_u16().ok().map(Value::U16),
-            TypeHint::U32 => src.try_get_u32().ok().map(Value::U32),
-            TypeHint::U64 => src.try_get_u64().ok().map(Value::U64),
-            TypeHint::U128 => src.try_get_u128().ok().map(Value::U128),
+            TypeHint::U16 => DecoderUtils::get_unsigned(src, self.encoding)?.map(Value::U16),
+            TypeHint::U32 => DecoderUtils::get_unsigned(src, self.encoding)?.map(Value::U32),
+            TypeHint::U64 => DecoderUtils::get_unsigned(src, self.encoding)?.map(Value::U64),
+            TypeHint::U128 => DecoderUtils::get_unsigned(src, self.encoding)?.map(Value::U128),
             TypeHint::I8 => src.try_get_i8().ok().map(Value::I8),
-            TypeHint::I16 => src.try_get_i16().ok().map(Value::I16),
-            TypeHint::I32 => src.try_get_i32().ok().map(Value::I32),
-            TypeHint::I64 => src.try_get_i64().ok().map(Value::I64),
-            TypeHint::I128 => src.try_get_i128().ok().map(Value::I128),
+            TypeHint::I16 => DecoderUtils::get_signed(src, self.encoding)?.map(Value::I16),
+            TypeHint::I32 => DecoderUtils::get_signed(src, self.encoding)?.map(Value::I32),
+            TypeHint::I64 => DecoderUtils::get_signed(src, self.encoding)?.map(Value::I64),
+            TypeHint::I128 => DecoderUtils::get_signed(src, self.encoding)?.map(Value::I128),
             TypeHint::F32 => src.try_get_f32().ok().map(Value::F32),
             TypeHint::F64 => src.try_get_f64().ok().map(Value::F64),
-            TypeHint::Usize => DecoderUtils::get_target_usize(src, self.pointer_width).map(Value::Usize),
-            TypeHint::Isize => DecoderUtils::get_target_isize(src, self.pointer_width).map(Value::Isize),
+            TypeHint::Usize => DecoderUtils::get_target_usize(src, self.encoding)?.map(Value::Usize),
+            TypeHint::Isize => DecoderUtils::get_target_isize(src, self.encoding)?.map(Value::Isize),
+            TypeHint::Pointer => DecoderUtils::get_target_usize(src, self.encoding)?
+                .map(|address| Value::Pointer { address, pointer_width: self.encoding.pointer_width }),
             TypeHint::Boolean => {
                 let Ok(bool_byte) = src.try_get_u8() else {
                     return Ok(None);
@@ -63,12 +70,60 @@ impl<'cache> ValueDecoder<'cache> {
 
                 Some(Value::Boolean(boolean))
             }
+            TypeHint::Duration => {
+                if src.remaining() < 12 {
+                    return Ok(None);
+                }
+
+                let secs = src.get_u64();
+                let nanos = src.get_u32();
+
+                if nanos >= 1_000_000_000 {
+                    let mut bytes = secs.to_be_bytes().to_vec();
+                    bytes.extend_from_slice(&nanos.to_be_bytes());
+                    return Err(RedefmtDecoderError::InvalidValueBytes(self.type_hint, bytes));
+                }
+
+                Some(Value::Duration(Duration::new(secs, nanos)))
+            }
+            TypeHint::Ipv4Addr => src
+                .try_get_u32()
+                .ok()
+                .map(|bits| Value::IpAddr(Ipv4Addr::from_bits(bits).into())),
+            TypeHint::Ipv6Addr => src
+                .try_get_u128()
+                .ok()
+                .map(|bits| Value::IpAddr(Ipv6Addr::from_bits(bits).into())),
+            TypeHint::SocketAddrV4 => {
+                if src.remaining() < 6 {
+                    return Ok(None);
+                }
+
+                let ip = Ipv4Addr::from_bits(src.get_u32());
+                let port = src.get_u16();
+
+                Some(Value::SocketAddr(SocketAddrV4::new(ip, port).into()))
+            }
+            TypeHint::SocketAddrV6 => {
+                if src.remaining() < 26 {
+                    return Ok(None);
+                }
+
+                let ip = Ipv6Addr::from_bits(src.get_u128());
+                let port = src.get_u16();
+                let flowinfo = src.get_u32();
+                let scope_id = src.get_u32();
+
+                Some(Value::SocketAddr(
+                    SocketAddrV6::new(ip, port, flowinfo, scope_id).into(),
+                ))
+            }
             TypeHint::Char => {
                 let Some(length) = self.get_or_store_u8_length(src) else {
                     return Ok(None);
                 };
 
-                if src.len() < length {
+                if src.remaining() < length {
                     return Ok(None);
                 }
 
@@ -89,7 +144,7 @@ impl<'cache> ValueDecoder<'cache> {
                     return Ok(None);
                 };
 
-                if src.len() < length {
+                if src.remaining() < length {
                     return Ok(None);
                 }
 
@@ -100,6 +155,17 @@ impl<'cache> ValueDecoder<'cache> {
 
                 Some(Value::String(string))
             }
+            TypeHint::Bytes => {
+                let Some(length) = self.get_or_store_usize_length(src)? else {
+                    return Ok(None);
+                };
+
+                if src.remaining() < length {
+                    return Ok(None);
+                }
+
+                Some(Value::Bytes(src.copy_to_bytes(length).to_vec()))
+            }
             TypeHint::Tuple => {
                 let Some(list_decoder) = self.get_or_store_u8_list(src) else {
                     return Ok(None);
@@ -128,9 +194,17 @@ impl<'cache> ValueDecoder<'cache> {
 
                 type_structure_decoder.decode(stores, src)?.map(Value::Type)
             }
+            TypeHint::BuiltinTypeStructure => {
+                let Some(type_structure_decoder) = self.get_or_store_builtin_type_structure_decoder(stores, src)?
+                else {
+                    return Ok(None);
+                };
+
+                type_structure_decoder.decode(stores, src)?.map(Value::Type)
+            }
             TypeHint::WriteStatements => self
                 .write_statements_decoder
-                .get_or_insert_with(|| WriteStatementsDecoder::new(self.pointer_width))
+                .get_or_insert_with(|| WriteStatementsDecoder::new(self.encoding))
                 .decode(stores, src)?
                 .map(Value::WriteStatements),
         };
@@ -138,7 +212,7 @@ impl<'cache> ValueDecoder<'cache> {
         Ok(maybe_simple_value)
     }
 
-    fn get_or_store_u8_length(&mut self, src: &mut BytesMut) -> Option<usize> {
+    fn get_or_store_u8_length(&mut self, src: &mut impl Buf) -> Option<usize> {
         if let Some(length) = self.length_context {
             return Some(length);
         };
@@ -152,7 +226,7 @@ impl<'cache> ValueDecoder<'cache> {
         Some(length)
     }
 
-    fn get_or_store_usize_length(&mut self, src: &mut BytesMut) -> Result<Option<usize>, RedefmtDecoderError> {
+    fn get_or_store_usize_length(&mut self, src: &mut impl Buf) -> Result<Option<usize>, RedefmtDecoderError> {
         if let Some(length) = self.length_context {
             return Ok(Some(length));
         };
@@ -166,13 +240,13 @@ impl<'cache> ValueDecoder<'cache> {
         Ok(Some(length))
     }
 
-    fn get_or_store_u8_list(&mut self, src: &mut BytesMut) -> Option<&mut ListValueDecoder<'cache>> {
+    fn get_or_store_u8_list(&mut self, src: &mut impl Buf) -> Option<&mut ListValueDecoder<'cache>> {
         if self.list_decoder.is_none() {
             let Ok(length) = src.try_get_u8().map(Into::into) else {
                 return None;
             };
 
-            self.list_decoder = Some(ListValueDecoder::new(self.pointer_width, length));
+            self.list_decoder = Some(ListValueDecoder::new(self.encoding, length));
         }
 
         self.list_decoder.as_mut()
@@ -180,21 +254,21 @@ impl<'cache> ValueDecoder<'cache> {
 
     fn get_or_store_usize_list(
         &mut self,
-        src: &mut BytesMut,
+        src: &mut impl Buf,
     ) -> Result<Option<&mut ListValueDecoder<'cache>>, RedefmtDecoderError> {
         if self.list_decoder.is_none() {
             let Some(length) = self.get_usize(src)? else {
                 return Ok(None);
             };
 
-            self.list_decoder = Some(ListValueDecoder::new(self.pointer_width, length));
+            self.list_decoder = Some(ListValueDecoder::new(self.encoding, length));
         }
 
         Ok(self.list_decoder.as_mut())
     }
 
-    fn get_usize(&self, src: &mut BytesMut) -> Result<Option<usize>, RedefmtDecoderError> {
-        let Some(length) = DecoderUtils::get_target_usize(src, self.pointer_width) else {
+    fn get_usize(&self, src: &mut impl Buf) -> Result<Option<usize>, RedefmtDecoderError> {
+        let Some(length) = DecoderUtils::get_target_usize(src, self.encoding)? else {
             return Ok(None);
         };
 
@@ -206,7 +280,7 @@ impl<'cache> ValueDecoder<'cache> {
     fn get_or_store_type_structure_decoder(
         &mut self,
         stores: &Stores<'cache>,
-        src: &mut BytesMut,
+        src: &mut impl Buf,
     ) -> Result<Option<&mut TypeStructureDecoder<'cache>>, RedefmtDecoderError> {
         if self.type_structure_decoder.is_none() {
             let Ok(crate_id) = src.try_get_u16().map(CrateId::new) else {
@@ -215,7 +289,28 @@ impl<'cache> ValueDecoder<'cache> {
 
             let type_structure_crate = stores.get_or_insert_crate(crate_id)?;
 
-            self.type_structure_decoder = Some(TypeStructureDecoder::new(self.pointer_width, type_structure_crate))
+            self.type_structure_decoder = Some(TypeStructureDecoder::new(self.encoding, type_structure_crate))
+        }
+
+        Ok(self.type_structure_decoder.as_mut())
+    }
+
+    fn get_or_store_builtin_type_structure_decoder(
+        &mut self,
+        stores: &Stores<'cache>,
+        src: &mut impl Buf,
+    ) -> Result<Option<&mut TypeStructureDecoder<'cache>>, RedefmtDecoderError> {
+        if self.type_structure_decoder.is_none() {
+            let Ok(repr) = src.try_get_u8() else {
+                return Ok(None);
+            };
+
+            let builtin =
+                BuiltinTypeStructure::from_repr(repr).ok_or(RedefmtDecoderError::UnknownBuiltinTypeStructure(repr))?;
+
+            let type_structure = stores.cache.builtin_type_structure.get_or_insert(builtin);
+
+            self.type_structure_decoder = Some(TypeStructureDecoder::resolved(self.encoding, type_structure))
         }
 
         Ok(self.type_structure_decoder.as_mut())
@@ -224,9 +319,11 @@ impl<'cache> ValueDecoder<'cache> {
 
 #[cfg(test)]
 mod tests {
+    use bytes::BytesMut;
     use redefmt_args::{identifier::AnyIdentifier, processed_format_string};
     use redefmt_core::{
-        Dispatcher, SimpleTestDispatcher,
+        Dispatcher, Format, Formatter, SimpleTestDispatcher,
+        frame::PointerWidth,
         write::{StatementWriterHint, WriteValue},
     };
     use redefmt_db::{
@@ -253,7 +350,7 @@ mod tests {
         let cache = RedefmtDecoderCache::default();
         let (_dir_guard, stores) = Stores::mock(&cache);
 
-        let mut value_decoder = ValueDecoder::new(PointerWidth::of_target(), TypeHint::Boolean);
+        let mut value_decoder = ValueDecoder::new(ValueEncoding::of_target(), TypeHint::Boolean);
         let mut bytes = BytesMut::from_iter([2]);
 
         let error = value_decoder.decode(&stores, &mut bytes).unwrap_err();
@@ -283,6 +380,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn varint() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, stores) = Stores::mock(&cache);
+
+        let mut dispatcher = SimpleTestDispatcher { varint: true, ..Default::default() };
+
+        let values: [&dyn WriteValue; 8] = [
+            &3u64,
+            &-2i32,
+            &u16::MAX,
+            &i128::MIN,
+            &usize::MAX,
+            &isize::MIN,
+            &"abc",
+            &[1u32, 300],
+        ];
+
+        for value in values {
+            value.write_value(&mut dispatcher);
+        }
+
+        let expected_values = [
+            Value::U64(3),
+            Value::I32(-2),
+            Value::U16(u16::MAX),
+            Value::I128(i128::MIN),
+            Value::Usize(usize::MAX as u64),
+            Value::Isize(isize::MIN as i64),
+            Value::String("abc".to_string()),
+            Value::List(vec![Value::U32(1), Value::U32(300)]),
+        ];
+
+        let encoding = ValueEncoding { varint: true, ..ValueEncoding::of_target() };
+        let mut bytes = dispatcher.bytes;
+
+        for expected_value in expected_values {
+            let type_hint = TypeHint::from_repr(bytes.get_u8()).unwrap();
+            let mut value_decoder = ValueDecoder::new(encoding, type_hint);
+
+            let actual_value = value_decoder.decode(&stores, &mut bytes).unwrap().unwrap();
+
+            assert_eq!(expected_value, actual_value);
+        }
+
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn varint_overflow_error() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, stores) = Stores::mock(&cache);
+
+        let encoding = ValueEncoding { varint: true, ..ValueEncoding::of_target() };
+        let mut value_decoder = ValueDecoder::new(encoding, TypeHint::U16);
+
+        // u16::MAX + 1
+        let mut bytes = BytesMut::from_iter([0x80, 0x80, 0x04]);
+
+        let error = value_decoder.decode(&stores, &mut bytes).unwrap_err();
+
+        assert!(matches!(error, RedefmtDecoderError::VarintOverflow(16)));
+    }
+
+    #[test]
+    fn pointer() {
+        let value = 1u8;
+        let pointer = &raw const value;
+
+        assert_value(TypeHint::Pointer, pointer, |pointer| Value::Pointer {
+            address: pointer.addr() as u64,
+            pointer_width: PointerWidth::of_target(),
+        });
+    }
+
     #[test]
     fn char() {
         assert_value(TypeHint::Char, 'x', Value::Char);
@@ -301,7 +473,7 @@ mod tests {
         bytes.put_u8(invalid_utf8_bytes.len() as u8);
         bytes.put_slice(&invalid_utf8_bytes);
 
-        let mut value_decoder = ValueDecoder::new(PointerWidth::of_target(), TypeHint::Char);
+        let mut value_decoder = ValueDecoder::new(ValueEncoding::of_target(), TypeHint::Char);
 
         let error = value_decoder.decode(&stores, &mut bytes).unwrap_err();
 
@@ -314,6 +486,16 @@ mod tests {
         assert_value(TypeHint::StringSlice, "🦀", |str| Value::String(str.to_string()));
     }
 
+    #[test]
+    fn bytes() {
+        assert_value(TypeHint::Bytes, redefmt_core::write::Bytes(&[0xde, 0xad]), |bytes| {
+            Value::Bytes(bytes.0.to_vec())
+        });
+        assert_value(TypeHint::Bytes, redefmt_core::write::Bytes(&[]), |_| {
+            Value::Bytes(Vec::new())
+        });
+    }
+
     #[test]
     fn string_invalid_utf8_error() {
         let cache = RedefmtDecoderCache::default();
@@ -324,13 +506,59 @@ mod tests {
         bytes.put_slice(&invalid_utf8_bytes.len().to_be_bytes());
         bytes.put_slice(&invalid_utf8_bytes);
 
-        let mut value_decoder = ValueDecoder::new(PointerWidth::of_target(), TypeHint::StringSlice);
+        let mut value_decoder = ValueDecoder::new(ValueEncoding::of_target(), TypeHint::StringSlice);
 
         let error = value_decoder.decode(&stores, &mut bytes).unwrap_err();
 
         assert!(matches!(error, RedefmtDecoderError::InvalidStringBytes(_)))
     }
 
+    #[test]
+    fn duration() {
+        assert_value(TypeHint::Duration, Duration::new(3, 500), Value::Duration);
+    }
+
+    #[test]
+    fn duration_invalid_nanos_error() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, stores) = Stores::mock(&cache);
+
+        let mut bytes = BytesMut::new();
+        bytes.put_u64(1);
+        bytes.put_u32(1_000_000_000);
+
+        let mut value_decoder = ValueDecoder::new(ValueEncoding::of_target(), TypeHint::Duration);
+
+        let error = value_decoder.decode(&stores, &mut bytes).unwrap_err();
+
+        assert!(matches!(
+            error,
+            RedefmtDecoderError::InvalidValueBytes(TypeHint::Duration, _)
+        ));
+    }
+
+    #[test]
+    fn ip_addr() {
+        assert_value(TypeHint::Ipv4Addr, Ipv4Addr::new(10, 0, 0, 1), |ip| {
+            Value::IpAddr(ip.into())
+        });
+        assert_value(TypeHint::Ipv6Addr, Ipv6Addr::LOCALHOST, |ip| Value::IpAddr(ip.into()));
+    }
+
+    #[test]
+    fn socket_addr() {
+        assert_value(
+            TypeHint::SocketAddrV4,
+            SocketAddrV4::new(Ipv4Addr::LOCALHOST, 80),
+            |addr| Value::SocketAddr(addr.into()),
+        );
+        assert_value(
+            TypeHint::SocketAddrV6,
+            SocketAddrV6::new(Ipv6Addr::LOCALHOST, 80, 2, 3),
+            |addr| Value::SocketAddr(addr.into()),
+        );
+    }
+
     #[test]
     fn tuple() {
         assert_value(TypeHint::Tuple, (10, "x"), |(num, str)| {
@@ -359,6 +587,11 @@ mod tests {
         });
     }
 
+    #[test]
+    fn empty_list() {
+        assert_value(TypeHint::List, [0u8; 0], |_| Value::List(vec![]));
+    }
+
     #[test]
     fn write_segments() {
         // setup
@@ -376,6 +609,7 @@ mod tests {
             append_newline: false,
             expected_positional_arg_count: 0,
             expected_named_args: vec![arg_name.clone()],
+            provided_arg_hints: Vec::new(),
         };
 
         let write_statement = WriteStatement(stored_expression.clone());
@@ -436,6 +670,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn generic_struct() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, stores) = Stores::mock(&cache);
+
+        let type_structure = TypeStructure {
+            name: "Foo".into(),
+            module_path: "x".into(),
+            generics: vec!["T".to_string(), "U".to_string()],
+            variant: TypeStructureVariant::Struct(StructVariant::Tuple(1)),
+            display: None,
+        };
+
+        let expected_value = Value::Type(TypeStructureValue {
+            name: "Foo",
+            module_path: "x",
+            generic_args: vec!["u8".to_string(), "&str".to_string()],
+            variant: TypeStructureVariantValue::Struct(StructVariantValue::Tuple(vec![Value::U8(1)])),
+            display: None,
+        });
+
+        let id_pair = seed_crate_record(&stores, &type_structure);
+
+        let mut dispatcher = SimpleTestDispatcher::default();
+        id_pair.write_value(&mut dispatcher);
+        "u8".write_raw(&mut dispatcher);
+        "&str".write_raw(&mut dispatcher);
+        1u8.write_value(&mut dispatcher);
+
+        assert_value_impl(stores, dispatcher, TypeHint::TypeStructure, expected_value);
+    }
+
     #[test]
     fn unit_enum() {
         assert_enum(0, [], StructVariantValue::Unit);
@@ -458,6 +724,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn builtin_type_structure() {
+        assert_builtin(
+            Some(true),
+            "Some",
+            StructVariantValue::Tuple(vec![Value::Boolean(true)]),
+        );
+        assert_builtin(None::<bool>, "None", StructVariantValue::Unit);
+        assert_builtin(
+            Err::<u8, _>("x"),
+            "Err",
+            StructVariantValue::Tuple(vec![Value::String("x".to_string())]),
+        );
+        assert_builtin(core::cmp::Ordering::Equal, "Equal", StructVariantValue::Unit);
+
+        fn assert_builtin(
+            value: impl Format,
+            expected_variant_name: &str,
+            expected_struct_variant: StructVariantValue,
+        ) {
+            let cache = RedefmtDecoderCache::default();
+            let (_dir_guard, stores) = Stores::mock(&cache);
+
+            let mut dispatcher = SimpleTestDispatcher::default();
+            value.fmt(&mut Formatter::new(&mut dispatcher)).unwrap();
+
+            let builtin = BuiltinTypeStructure::from_repr(dispatcher.bytes[1]).unwrap();
+            let type_structure = TypeStructure::builtin(builtin);
+
+            let expected_value = Value::Type(TypeStructureValue {
+                name: &type_structure.name,
+                module_path: &type_structure.module_path,
+                generic_args: vec![],
+                variant: TypeStructureVariantValue::Enum((expected_variant_name, expected_struct_variant)),
+                display: None,
+            });
+
+            assert_value_impl(stores, dispatcher, TypeHint::BuiltinTypeStructure, expected_value);
+        }
+    }
+
+    #[test]
+    fn unknown_builtin_type_structure_error() {
+        let cache = RedefmtDecoderCache::default();
+        let (_dir_guard, stores) = Stores::mock(&cache);
+
+        let mut value_decoder = ValueDecoder::new(ValueEncoding::of_target(), TypeHint::BuiltinTypeStructure);
+        let mut bytes = BytesMut::from_iter([u8::MAX]);
+
+        let error = value_decoder.decode(&stores, &mut bytes).unwrap_err();
+
+        assert!(matches!(
+            error,
+            RedefmtDecoderError::UnknownBuiltinTypeStructure(u8::MAX)
+        ));
+    }
+
     fn assert_value<'cache, T: WriteValue>(
         type_hint: TypeHint,
         encoded_value: T,
@@ -486,7 +809,7 @@ mod tests {
 
         assert_eq!(dispatched_type_hint, type_hint);
 
-        let mut value_decoder = ValueDecoder::new(PointerWidth::of_target(), type_hint);
+        let mut value_decoder = ValueDecoder::new(ValueEncoding::of_target(), type_hint);
 
         let actual_value = value_decoder.decode(&stores, &mut dispatched_bytes).unwrap().unwrap();
 
@@ -506,12 +829,18 @@ mod tests {
 
         let type_structure = TypeStructure {
             name: type_name.into(),
+            module_path: "x".into(),
+            generics: vec![],
             variant: TypeStructureVariant::Struct(struct_variant),
+            display: None,
         };
 
         let expected_value = Value::Type(TypeStructureValue {
             name: type_name,
+            module_path: "x",
+            generic_args: vec![],
             variant: TypeStructureVariantValue::Struct(expected_struct_variant_value),
+            display: None,
         });
 
         let id_pair = seed_crate_record(&stores, &type_structure);
@@ -550,12 +879,18 @@ mod tests {
 
         let type_structure = TypeStructure {
             name: type_name.into(),
+            module_path: "x".into(),
+            generics: vec![],
             variant: TypeStructureVariant::Enum(enum_variants),
+            display: None,
         };
 
         let expected_value = Value::Type(TypeStructureValue {
             name: type_name,
+            module_path: "x",
+            generic_args: vec![],
             variant: TypeStructureVariantValue::Enum((&expected_variant_name, expected_struct_variant)),
+            display: None,
         });
 
         let write_id = seed_crate_record(&stores, &type_structure);
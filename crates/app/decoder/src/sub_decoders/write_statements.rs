@@ -1,6 +1,5 @@
-use bytes::{Buf, BytesMut};
+use bytes::Buf;
 use redefmt_core::{
-    frame::PointerWidth,
     identifiers::{CrateId, WriteStatementId},
     write::StatementWriterHint,
 };
@@ -15,15 +14,15 @@ pub enum WriteStatementsDecoderWants<'cache> {
 }
 
 pub struct WriteStatementsDecoder<'cache> {
-    pointer_width: PointerWidth,
+    encoding: ValueEncoding,
     stage: WriteStatementsDecoderWants<'cache>,
     decoded_statements: Vec<WriteStatementValue<'cache>>,
 }
 
 impl<'cache> WriteStatementsDecoder<'cache> {
-    pub fn new(pointer_width: PointerWidth) -> Self {
+    pub fn new(encoding: ValueEncoding) -> Self {
         Self {
-            pointer_width,
+            encoding,
             stage: WriteStatementsDecoderWants::WriterHint,
             decoded_statements: Default::default(),
         }
@@ -32,7 +31,7 @@ impl<'cache> WriteStatementsDecoder<'cache> {
     pub fn decode(
         &mut self,
         stores: &Stores<'cache>,
-        src: &mut BytesMut,
+        src: &mut impl Buf,
     ) -> Result<Option<Vec<WriteStatementValue<'cache>>>, RedefmtDecoderError> {
         let stage = std::mem::replace(&mut self.stage, WriteStatementsDecoderWants::WriterHint);
 
@@ -92,7 +91,7 @@ impl<'cache> WriteStatementsDecoder<'cache> {
                     .write_statement
                     .get_or_insert(write_statement_id, crate_context)?;
 
-                let segment_decoder = SegmentsDecoder::new(self.pointer_width, &write_statement.0);
+                let segment_decoder = SegmentsDecoder::new(self.encoding, &write_statement.0);
 
                 self.stage = WriteStatementsDecoderWants::Value(Box::new(segment_decoder));
 
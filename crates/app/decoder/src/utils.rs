@@ -1,6 +1,7 @@
-use bytes::{Buf, BytesMut};
+use bytes::Buf;
 use redefmt_core::{
     frame::{PointerWidth, TypeHint},
+    varint,
     write::StatementWriterHint,
 };
 
@@ -9,9 +10,24 @@ use crate::*;
 pub struct DecoderUtils;
 
 impl DecoderUtils {
-    pub fn get_target_usize(src: &mut BytesMut, pointer_width: PointerWidth) -> Option<u64> {
-        if src.len() < pointer_width.size() {
-            return None;
+    pub fn get_target_usize(src: &mut impl Buf, encoding: ValueEncoding) -> Result<Option<u64>, RedefmtDecoderError> {
+        let pointer_width = encoding.pointer_width;
+
+        if encoding.varint {
+            let Some(num) = Self::get_varint(src)? else {
+                return Ok(None);
+            };
+
+            let bits = pointer_width.size() as u32 * 8;
+
+            return match num >> bits {
+                0 => Ok(Some(num as u64)),
+                _ => Err(RedefmtDecoderError::VarintOverflow(bits)),
+            };
+        }
+
+        if src.remaining() < pointer_width.size() {
+            return Ok(None);
         }
 
         let num = match pointer_width {
@@ -20,12 +36,28 @@ impl DecoderUtils {
             PointerWidth::U64 => src.get_u64(),
         };
 
-        Some(num)
+        Ok(Some(num))
     }
 
-    pub fn get_target_isize(src: &mut BytesMut, pointer_width: PointerWidth) -> Option<i64> {
-        if src.len() < pointer_width.size() {
-            return None;
+    pub fn get_target_isize(src: &mut impl Buf, encoding: ValueEncoding) -> Result<Option<i64>, RedefmtDecoderError> {
+        let pointer_width = encoding.pointer_width;
+
+        if encoding.varint {
+            let Some(num) = Self::get_varint(src)?.map(varint::zigzag_decode) else {
+                return Ok(None);
+            };
+
+            let bits = pointer_width.size() as u32 * 8;
+
+            // Sign extended bits beyond the pointer width must all match the sign bit
+            return match num >> (bits - 1) {
+                0 | -1 => Ok(Some(num as i64)),
+                _ => Err(RedefmtDecoderError::VarintOverflow(bits)),
+            };
+        }
+
+        if src.remaining() < pointer_width.size() {
+            return Ok(None);
         }
 
         let num = match pointer_width {
@@ -34,10 +66,50 @@ impl DecoderUtils {
             PointerWidth::U64 => src.get_i64(),
         };
 
-        Some(num)
+        Ok(Some(num))
     }
 
-    pub fn get_type_hint(src: &mut BytesMut) -> Result<Option<TypeHint>, RedefmtDecoderError> {
+    /// Unsigned integer, written with `to_be_bytes` unless varints are used
+    pub fn get_unsigned<T: TryFrom<u128> + FromBeBytes>(
+        src: &mut impl Buf,
+        encoding: ValueEncoding,
+    ) -> Result<Option<T>, RedefmtDecoderError> {
+        match encoding.varint {
+            true => Self::get_varint(src)?
+                .map(|num| T::try_from(num).map_err(|_| RedefmtDecoderError::VarintOverflow(T::BITS)))
+                .transpose(),
+            false => Ok(T::try_get_be(src)),
+        }
+    }
+
+    /// Signed integer, written with `to_be_bytes` unless zigzag encoded varints are used
+    pub fn get_signed<T: TryFrom<i128> + FromBeBytes>(
+        src: &mut impl Buf,
+        encoding: ValueEncoding,
+    ) -> Result<Option<T>, RedefmtDecoderError> {
+        match encoding.varint {
+            true => Self::get_varint(src)?
+                .map(|num| {
+                    T::try_from(varint::zigzag_decode(num)).map_err(|_| RedefmtDecoderError::VarintOverflow(T::BITS))
+                })
+                .transpose(),
+            false => Ok(T::try_get_be(src)),
+        }
+    }
+
+    pub fn get_varint(src: &mut impl Buf) -> Result<Option<u128>, RedefmtDecoderError> {
+        let Some((num, length)) =
+            varint::decode(src.chunk()).map_err(|_| RedefmtDecoderError::VarintOverflow(u128::BITS))?
+        else {
+            return Ok(None);
+        };
+
+        src.advance(length);
+
+        Ok(Some(num))
+    }
+
+    pub fn get_type_hint(src: &mut impl Buf) -> Result<Option<TypeHint>, RedefmtDecoderError> {
         let Ok(type_hint_repr) = src.try_get_u8() else {
             return Ok(None);
         };
@@ -47,7 +119,7 @@ impl DecoderUtils {
             .map(Some)
     }
 
-    pub fn get_statement_writer_hint(src: &mut BytesMut) -> Result<Option<StatementWriterHint>, RedefmtDecoderError> {
+    pub fn get_statement_writer_hint(src: &mut impl Buf) -> Result<Option<StatementWriterHint>, RedefmtDecoderError> {
         let Ok(hint) = src.try_get_u8() else {
             return Ok(None);
         };
@@ -57,3 +129,35 @@ impl DecoderUtils {
             .map(Some)
     }
 }
+
+/// Integers decodable from their `to_be_bytes` representation
+pub trait FromBeBytes: Sized {
+    const BITS: u32;
+
+    fn try_get_be(src: &mut impl Buf) -> Option<Self>;
+}
+
+macro_rules! from_be_bytes_impl {
+    ($(($type:ty, $try_get:ident),)*) => {
+        $(
+            impl FromBeBytes for $type {
+                const BITS: u32 = <$type>::BITS;
+
+                fn try_get_be(src: &mut impl Buf) -> Option<Self> {
+                    src.$try_get().ok()
+                }
+            }
+        )*
+    };
+}
+
+from_be_bytes_impl!(
+    (u16, try_get_u16),
+    (u32, try_get_u32),
+    (u64, try_get_u64),
+    (u128, try_get_u128),
+    (i16, try_get_i16),
+    (i32, try_get_i32),
+    (i64, try_get_i64),
+    (i128, try_get_i128),
+);
@@ -1,4 +1,10 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+
 use redefmt_args::{identifier::AnyIdentifier, processor::ProcessedFormatString};
+use redefmt_core::frame::PointerWidth;
 use redefmt_db::statement_table::stored_format_expression::StoredFormatExpression;
 
 #[derive(Debug, Default, PartialEq)]
@@ -20,6 +26,10 @@ impl<'cache> DecodedValues<'cache> {
             && self.named.len() == stored_expression.expected_named_args.len()
     }
 
+    pub(crate) fn len(&self) -> usize {
+        self.positional.len() + self.named.len()
+    }
+
     pub(crate) fn push(
         &mut self,
         decoded_value: Value<'cache>,
@@ -56,6 +66,12 @@ pub enum Value<'cache> {
     F64(f64),
     Char(char),
     String(String),
+    Duration(Duration),
+    IpAddr(IpAddr),
+    SocketAddr(SocketAddr),
+    // Target address, formatted with its pointer width
+    Pointer { address: u64, pointer_width: PointerWidth },
+    Bytes(Vec<u8>),
     // Reused for array, vec and slice containing both single and dyn values.
     List(Vec<Value<'cache>>),
     Tuple(Vec<Value<'cache>>),
@@ -73,7 +89,11 @@ pub struct WriteStatementValue<'cache> {
 #[derive(Debug, PartialEq)]
 pub struct TypeStructureValue<'cache> {
     pub name: &'cache str,
+    pub module_path: &'cache str,
+    /// `core::any::type_name` of each type parameter
+    pub generic_args: Vec<String>,
     pub variant: TypeStructureVariantValue<'cache>,
+    pub display: Option<&'cache ProcessedFormatString<'static>>,
 }
 
 #[derive(Debug, PartialEq)]
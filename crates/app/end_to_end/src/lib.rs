@@ -97,8 +97,15 @@ mod tests {
         let mut printer = PrettyPrinter::new(printer_config);
 
         assert_print!(dispatcher, decoder, printer, redefmt::info!("10"), "INFO - 10\n");
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::info!(target: "net", "11"),
+            "INFO - 11\n"
+        );
 
-        logger.assert_logs(&["INFO - 10"]);
+        logger.assert_logs(&["INFO - 10", "INFO - 11"]);
     }
 }
 
@@ -265,7 +272,7 @@ mod tests {
             decoder,
             printer,
             redefmt::print!("{FooWrite:?}"),
-            "13 [NONE] - {crate_name}: \"x\\ny\""
+            "13 [NONE] - {crate_name}: x\ny"
         );
 
         // derive unit struct
@@ -388,6 +395,445 @@ mod tests {
         #[allow(unused)]
         #[derive(redefmt::Format)]
         enum FooEnumInfallible {}
+
+        // CRC trailer
+        GlobalLogger::enable_crc();
+        let value = FooEnum::Named { a: 1, b: 2 };
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?}"),
+            "24 [NONE] - {crate_name}: Named {{ a: 1, b: 2 }}"
+        );
+
+        // sequence numbers
+        GlobalLogger::enable_sequence();
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?}"),
+            "25 [NONE] - {crate_name}: Named {{ a: 1, b: 2 }}"
+        );
+
+        redefmt::print!("{value:?}");
+        dispatcher.take_bytes();
+
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?}"),
+            "1 frames lost\n27 [NONE] - {crate_name}: Named {{ a: 1, b: 2 }}"
+        );
+
+        // sequence numbers wrap around, skipping those from 3 to u16::MAX - 1
+        for _ in 3..u16::MAX {
+            redefmt::print!("{value:?}");
+        }
+        dispatcher.take_bytes();
+
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?}"),
+            "65532 frames lost\n65560 [NONE] - {crate_name}: Named {{ a: 1, b: 2 }}"
+        );
+
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?}"),
+            "65561 [NONE] - {crate_name}: Named {{ a: 1, b: 2 }}"
+        );
+
+        // runtime level filtering
+        GlobalLogger::set_max_level(redefmt::LevelFilter::Warn);
+
+        let value = 10;
+
+        let mut evaluated = false;
+        redefmt::info!("{}", {
+            evaluated = true;
+            value
+        });
+        assert!(!evaluated);
+        dispatcher.assert_bytes(&[]);
+
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::warn!("{value}"),
+            "65562 [WARN] - {crate_name}: {value}\n"
+        );
+
+        GlobalLogger::set_max_level(redefmt::LevelFilter::Trace);
+
+        // crate level filtering
+        const CRATE_ID: redefmt::CrateId = redefmt::crate_id!();
+
+        GlobalLogger::set_crate_max_level(CRATE_ID, redefmt::LevelFilter::Error).unwrap();
+        redefmt::warn!("{value}");
+        dispatcher.assert_bytes(&[]);
+
+        GlobalLogger::reset_crate_max_level(CRATE_ID);
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::warn!("{value}"),
+            "65563 [WARN] - {crate_name}: {value}\n"
+        );
+
+        // module path and target
+        let printer_config =
+            PrettyPrinterConfig::new_with_format(PrintStampConfig::Counter, "{module} {target}: {statement}").unwrap();
+        let mut printer = PrettyPrinter::new(printer_config);
+
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::info!("{value}"),
+            "redefmt_end_to_end redefmt_end_to_end: {value}\n"
+        );
+
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::info!(target: "net", "{value}"),
+            "redefmt_end_to_end net: {value}\n"
+        );
+
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::log!(target: "net", Level::Info, "{value}"),
+            "redefmt_end_to_end net: {value}\n"
+        );
+
+        let printer_config =
+            PrettyPrinterConfig::new_with_format(PrintStampConfig::Counter, "{column}: {statement}").unwrap();
+        let mut printer = PrettyPrinter::new(printer_config);
+
+        assert_print!(dispatcher, decoder, printer, redefmt::info!("{value}"), "53: {value}\n");
+
+        // builtin type structures
+        let printer_config = PrettyPrinterConfig::new_with_format(PrintStampConfig::Counter, "{statement}").unwrap();
+        let mut printer = PrettyPrinter::new(printer_config);
+
+        let value = Some(FooEnum::Tuple(1, 2));
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?}"),
+            "Some(Tuple(1, 2))"
+        );
+
+        let value: Result<u8, &str> = Err("x");
+        assert_print!(dispatcher, decoder, printer, redefmt::print!("{value:?}"), "Err(\"x\")");
+
+        let value = 1.cmp(&2);
+        assert_print!(dispatcher, decoder, printer, redefmt::print!("{value:?}"), "Less");
+
+        // core library types
+        let value = core::time::Duration::from_micros(1500);
+        assert_print!(dispatcher, decoder, printer, redefmt::print!("{value:.1?}"), "1.5ms");
+
+        let value = core::net::SocketAddr::from(([10, 0, 0, 1], 8080));
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value}"),
+            "10.0.0.1:8080"
+        );
+
+        let value = core::num::NonZeroU8::new(3);
+        assert_print!(dispatcher, decoder, printer, redefmt::print!("{value:?}"), "Some(3)");
+
+        // pointers, encoded as the target address
+        let value = 1u8;
+        let reference = &value;
+        let pointer = &raw const value;
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{reference:p}"),
+            "{pointer:p}"
+        );
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{:#p} {:?} {:p}", reference, pointer, &value),
+            "{pointer:#p} {pointer:?} {pointer:p}"
+        );
+
+        // derive attributes
+        fn fmt_masked(_: &&str, f: &mut redefmt::Formatter) -> core::fmt::Result {
+            redefmt::Format::fmt(&"***", f)
+        }
+
+        #[derive(redefmt::Format)]
+        struct FooAttributes<'a> {
+            #[redefmt(rename = "user")]
+            name: &'a str,
+            #[redefmt(with = fmt_masked)]
+            password: &'a str,
+            #[redefmt(skip)]
+            _buffer: [u8; 64],
+        }
+
+        let value = FooAttributes { name: "x", password: "y", _buffer: [0; 64] };
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?}"),
+            "FooAttributes {{ user: \"x\", password: \"***\" }}"
+        );
+
+        struct NotFormat;
+
+        #[derive(redefmt::Format)]
+        #[redefmt(bound = "")]
+        enum FooEnumAttributes<T> {
+            #[redefmt(rename = "Skipped")]
+            Tuple(#[redefmt(skip)] T, usize),
+            Named {
+                #[redefmt(skip)]
+                _inner: T,
+            },
+        }
+
+        let value = FooEnumAttributes::Tuple(NotFormat, 1);
+        assert_print!(dispatcher, decoder, printer, redefmt::print!("{value:?}"), "Skipped(1)");
+
+        let value = FooEnumAttributes::Named { _inner: NotFormat };
+        assert_print!(dispatcher, decoder, printer, redefmt::print!("{value:?}"), "Named");
+
+        #[derive(redefmt::Format)]
+        #[redefmt(display = "({x}, {y:.1})")]
+        struct FooDisplay {
+            x: i32,
+            y: f32,
+        }
+
+        let value = FooDisplay { x: 1, y: 2.5 };
+        assert_print!(dispatcher, decoder, printer, redefmt::print!("{value}"), "(1, 2.5)");
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?}"),
+            "FooDisplay {{ x: 1, y: 2.5 }}"
+        );
+
+        #[derive(redefmt::Format)]
+        #[redefmt(type_names)]
+        struct FooWrapper<T>(T);
+
+        #[derive(redefmt::Format)]
+        struct FooUnnamed<T>(T);
+
+        let value = FooWrapper(Some(1u8));
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?}"),
+            "FooWrapper(Some(1))"
+        );
+
+        let printer_config = PrettyPrinterConfig::new_with_format(PrintStampConfig::Counter, "{statement}")
+            .unwrap()
+            .with_full_type_names();
+        let mut printer = PrettyPrinter::new(printer_config);
+
+        // Module path is inferred from the file, inline modules aren't accounted for
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?}"),
+            "redefmt_end_to_end::FooWrapper<core::option::Option<u8>>(core::option::Option::Some(1))"
+        );
+
+        // type parameter names are only dispatched with `redefmt(type_names)`
+        let value = FooUnnamed(1u8);
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?}"),
+            "redefmt_end_to_end::FooUnnamed(1)"
+        );
+
+        // `core::fmt` conformance of derived types
+        let printer_config = PrettyPrinterConfig::new_with_format(PrintStampConfig::Counter, "{statement}").unwrap();
+        let mut printer = PrettyPrinter::new(printer_config);
+
+        #[derive(Debug, redefmt::Format)]
+        struct ConformanceUnit;
+
+        #[derive(Debug, redefmt::Format)]
+        struct ConformanceEmpty {}
+
+        #[derive(Debug, redefmt::Format)]
+        struct ConformanceTuple(u8, &'static str);
+
+        #[derive(Debug, redefmt::Format)]
+        enum ConformanceEnum {
+            Unit,
+            Tuple(f32),
+            Named { inner: ConformanceTuple },
+        }
+
+        struct ConformanceWrite;
+
+        impl core::fmt::Debug for ConformanceWrite {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                writeln!(f, "x")?;
+                write!(f, "y")
+            }
+        }
+
+        impl redefmt::Format for ConformanceWrite {
+            fn fmt(&self, f: &mut redefmt::Formatter) -> ::core::fmt::Result {
+                let mut s = f.write_statements();
+                redefmt::writeln!(s, "x")?;
+                redefmt::write!(s, "y")?;
+                Ok(())
+            }
+        }
+
+        #[derive(Debug, redefmt::Format)]
+        struct ConformanceNamed {
+            unit: ConformanceUnit,
+            empty: ConformanceEmpty,
+            option: Option<ConformanceTuple>,
+            list: [u16; 3],
+            empty_list: [u16; 0],
+            tuple: (bool, char, &'static str),
+            unit_variant: ConformanceEnum,
+            named_variant: ConformanceEnum,
+            written: ConformanceWrite,
+            result: Result<ConformanceEnum, u8>,
+        }
+
+        assert_debug_conformance!(dispatcher, decoder, printer, ConformanceUnit);
+        assert_debug_conformance!(dispatcher, decoder, printer, ConformanceEmpty {});
+        assert_debug_conformance!(dispatcher, decoder, printer, ConformanceTuple(1, "a\"b"));
+        assert_debug_conformance!(dispatcher, decoder, printer, ConformanceEnum::Tuple(1.5));
+        assert_debug_conformance!(dispatcher, decoder, printer, ConformanceWrite);
+        assert_debug_conformance!(
+            dispatcher,
+            decoder,
+            printer,
+            ConformanceNamed {
+                unit: ConformanceUnit,
+                empty: ConformanceEmpty {},
+                option: Some(ConformanceTuple(2, "b")),
+                list: [1, 2, 3],
+                empty_list: [],
+                tuple: (true, '\n', "c"),
+                unit_variant: ConformanceEnum::Unit,
+                named_variant: ConformanceEnum::Named { inner: ConformanceTuple(3, "d") },
+                written: ConformanceWrite,
+                result: Ok(ConformanceEnum::Tuple(-0.5)),
+            }
+        );
+
+        // hex dumps
+        let printer_config = PrettyPrinterConfig::new_with_format(PrintStampConfig::Counter, "{statement}")
+            .unwrap()
+            .with_list_separator(" ");
+        let mut printer = PrettyPrinter::new(printer_config);
+
+        let value = [0xde_u8, 0xad, 0xbe, 0xef];
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:02X}"),
+            "DE AD BE EF"
+        );
+
+        let value = value.as_slice();
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:#x}"),
+            "0xde 0xad 0xbe 0xef"
+        );
+
+        let value = redefmt::Bytes(b"redefmt hexdump\x00\x01");
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value}"),
+            "00000000: 7265 6465 666d 7420 6865 7864 756d 7000  redefmt hexdump.\n\
+             00000010: 01                                       ."
+        );
+
+        // statically known argument types
+        let value = 300u32;
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{} {} {:?} {} {}", 1u8, -2.5, 'x', "y", value as u16),
+            "1 -2.5 'x' y 300"
+        );
+
+        // varints
+        GlobalLogger::enable_varint();
+
+        let value = (3u64, -2i32, u128::MAX, [1usize, 300]);
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?}"),
+            "(3, -2, 340282366920938463463374607431768211455, [1, 300])"
+        );
+
+        let value = FooEnum::Named { a: 1, b: 2 };
+        assert_print!(
+            dispatcher,
+            decoder,
+            printer,
+            redefmt::print!("{value:?} {}", "x"),
+            "Named {{ a: 1, b: 2 }} x"
+        );
+
+        // statements embedded in the test binary itself
+        #[cfg(feature = "embed")]
+        {
+            let embedded_decoder_cache = RedefmtDecoderCache::default();
+            let test_binary = std::env::current_exe().unwrap();
+            let mut decoder = RedefmtDecoder::from_elf(&embedded_decoder_cache, &test_binary).unwrap();
+
+            let value = FooEnum::Named { a: 1, b: 2 };
+            assert_print!(
+                dispatcher,
+                decoder,
+                printer,
+                redefmt::print!("{value:?} {}", 'y'),
+                "Named {{ a: 1, b: 2 }} y"
+            );
+        }
     }
 }
 
@@ -455,6 +901,22 @@ mod test_deferred {
         }};
     }
 
+    /// Compare both `{:?}` and `{:#?}` against `core::fmt`, expects a "{statement}" printer format
+    #[macro_export]
+    macro_rules! assert_debug_conformance {
+        ($dispatcher:expr, $decoder:expr, $printer:expr, $value:expr) => {{
+            let value = $value;
+
+            redefmt::print!("{:?}", value);
+            let expected = format!("{:?}", value);
+            $crate::test_deferred::assert_print_impl(&mut $dispatcher, &mut $decoder, &mut $printer, &expected);
+
+            redefmt::print!("{:#?}", value);
+            let expected = format!("{:#?}", value);
+            $crate::test_deferred::assert_print_impl(&mut $dispatcher, &mut $decoder, &mut $printer, &expected);
+        }};
+    }
+
     pub fn assert_print_impl(
         dispatcher: &mut SharedTestDispatcher,
         decoder: &mut RedefmtDecoder,
@@ -10,8 +10,16 @@ pub use deferred::*;
 mod deferred {
     #[doc(hidden)]
     pub use redefmt_core::identifiers;
-    pub use redefmt_core::{Format, Formatter, frame::Level, logger};
-    pub use redefmt_macros::{Format, write, writeln};
+    #[doc(hidden)]
+    pub use redefmt_core::write::{FormatPointer, RawValue, ReferencedArgument, ReferencedPointee, ReferencedPointer};
+    pub use redefmt_core::{
+        Format, Formatter,
+        frame::{Level, LevelFilter},
+        identifiers::CrateId,
+        logger,
+        write::Bytes,
+    };
+    pub use redefmt_macros::{Format, crate_id, write, writeln};
 }
 
 #[allow(unused_imports)]
@@ -0,0 +1,25 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+
+use crate::*;
+
+/// Expands to the `CrateId` registered for the calling crate, usable in const contexts
+pub fn macro_impl(token_stream: TokenStream) -> TokenStream {
+    let span = Span::call_site();
+
+    if !token_stream.is_empty() {
+        return syn::Error::new(span, "crate_id!() takes no arguments")
+            .into_compile_error()
+            .into();
+    }
+
+    let db_clients = db_clients!(span);
+
+    let crate_id_inner = db_clients.crate_id.as_ref();
+
+    quote! {
+        ::redefmt::identifiers::CrateId::new(#crate_id_inner)
+    }
+    .into()
+}
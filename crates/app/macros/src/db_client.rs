@@ -1,7 +1,9 @@
+use proc_macro2::TokenStream as TokenStream2;
 use redefmt_core::identifiers::CrateId;
 use redefmt_db::{
     CrateDb, DbClient, StateDir, Table,
-    crate_table::{Crate, CrateName, CrateTable},
+    crate_table::{Crate, CrateName, CrateTable, CrateVersion},
+    statement_table::StatementTable,
 };
 
 use crate::*;
@@ -9,6 +11,7 @@ use crate::*;
 pub struct DbClients {
     pub crate_db: DbClient<CrateDb>,
     pub crate_id: CrateId,
+    pub krate: Crate<'static>,
 }
 
 impl DbClients {
@@ -17,25 +20,45 @@ impl DbClients {
 
         let main_db = DbClient::new_main(&state_dir)?;
 
-        let crate_name = Self::crate_name()?;
+        let krate = Self::krate()?;
 
-        let crate_db = DbClient::new_crate(&state_dir, &crate_name)?;
+        let crate_db = DbClient::new_crate(&state_dir, &krate)?;
 
-        let crate_id = match main_db.find_crate_by_name(&crate_name)? {
-            Some((id, _)) => id,
-            None => {
-                let record = Crate::new(crate_name);
-                main_db.insert(&record)?
-            }
+        let crate_id = match main_db.find_crate(&krate)? {
+            Some(id) => id,
+            None => main_db.insert(&krate)?,
         };
 
-        Ok(Self { crate_db, crate_id })
+        Ok(Self { crate_db, crate_id, krate })
     }
 
-    fn crate_name() -> Result<CrateName<'static>, RedefmtMacroError> {
+    /// Registers the statement, along with the tokens which embed it in the
+    /// target binary if the `embed` feature is enabled
+    pub fn insert<T: StatementTable>(&self, statement: &T) -> Result<(T::Id, TokenStream2), RedefmtMacroError>
+    where
+        T::Id: Copy + AsRef<u16>,
+        DbClient<CrateDb>: Table<T>,
+    {
+        let id = self.crate_db.insert(statement)?;
+
+        let embedded_statement = match cfg!(feature = "embed") {
+            true => EmbeddedStatement::tokens(self.crate_id, &self.krate, id, statement)?,
+            false => TokenStream2::new(),
+        };
+
+        Ok((id, embedded_statement))
+    }
+
+    /// Several versions of the same package may be present in one build
+    /// graph, each being registered as a separate crate.
+    fn krate() -> Result<Crate<'static>, RedefmtMacroError> {
         let name_str = std::env::var("CARGO_PKG_NAME")?;
         let crate_name = CrateName::new(name_str)?;
-        Ok(crate_name)
+
+        let version_str = std::env::var("CARGO_PKG_VERSION").map_err(RedefmtMacroError::CrateVersionEnv)?;
+        let crate_version = CrateVersion::new(version_str)?;
+
+        Ok(Crate::new(crate_name).with_version(crate_version))
     }
 }
 
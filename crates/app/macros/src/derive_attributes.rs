@@ -0,0 +1,165 @@
+use syn::{Attribute, LitStr, Path, Token, WherePredicate, punctuated::Punctuated};
+
+const ATTRIBUTE_NAME: &str = "redefmt";
+
+/// `#[redefmt(...)]` attributes on the type deriving `Format`
+#[derive(Default)]
+pub struct ContainerAttributes {
+    /// Replaces the `::redefmt::Format` bounds otherwise added to each type parameter
+    pub bound: Option<Punctuated<WherePredicate, Token![,]>>,
+    /// Format string used for `{}`, with the struct fields as its arguments
+    pub display: Option<LitStr>,
+    /// Dispatch the `core::any::type_name` of each type parameter
+    pub type_names: bool,
+}
+
+impl ContainerAttributes {
+    pub fn parse(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut container_attributes = Self::default();
+
+        for attribute in redefmt_attributes(attributes) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("bound") {
+                    let bound = meta.value()?.parse::<LitStr>()?;
+                    container_attributes.bound = Some(bound.parse_with(Punctuated::parse_terminated)?);
+                    Ok(())
+                } else if meta.path.is_ident("display") {
+                    container_attributes.display = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("type_names") {
+                    container_attributes.type_names = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported redefmt container attribute"))
+                }
+            })?;
+        }
+
+        Ok(container_attributes)
+    }
+}
+
+/// `#[redefmt(...)]` attributes on enum variants
+#[derive(Default)]
+pub struct VariantAttributes {
+    pub rename: Option<String>,
+}
+
+impl VariantAttributes {
+    pub fn parse(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut variant_attributes = Self::default();
+
+        for attribute in redefmt_attributes(attributes) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    variant_attributes.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported redefmt variant attribute"))
+                }
+            })?;
+        }
+
+        Ok(variant_attributes)
+    }
+}
+
+/// `#[redefmt(...)]` attributes on struct and variant fields
+#[derive(Default)]
+pub struct FieldAttributes {
+    /// Field is neither encoded nor included in the type structure
+    pub skip: bool,
+    pub rename: Option<String>,
+    /// Formatted with `fn(&T, &mut ::redefmt::Formatter) -> ::core::fmt::Result`
+    pub with: Option<Path>,
+}
+
+impl FieldAttributes {
+    pub fn parse(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut field_attributes = Self::default();
+
+        for attribute in redefmt_attributes(attributes) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    field_attributes.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    field_attributes.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("with") {
+                    field_attributes.with = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported redefmt field attribute"))
+                }
+            })?;
+        }
+
+        Ok(field_attributes)
+    }
+}
+
+fn redefmt_attributes(attributes: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident(ATTRIBUTE_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    use super::*;
+
+    #[test]
+    fn field_attributes() {
+        let attributes: Vec<Attribute> = parse_quote! {
+            #[doc = "ignored"]
+            #[redefmt(skip, rename = "y")]
+            #[redefmt(with = crate::fmt_x)]
+        };
+
+        let field_attributes = FieldAttributes::parse(&attributes).unwrap();
+
+        assert!(field_attributes.skip);
+        assert_eq!(Some("y"), field_attributes.rename.as_deref());
+
+        let with = field_attributes.with.unwrap();
+        assert_eq!("crate :: fmt_x", with.to_token_stream().to_string());
+    }
+
+    #[test]
+    fn container_bound() {
+        let attributes: Vec<Attribute> = parse_quote! { #[redefmt(bound = "T: Copy, U: ::redefmt::Format")] };
+
+        let container_attributes = ContainerAttributes::parse(&attributes).unwrap();
+
+        assert_eq!(2, container_attributes.bound.unwrap().len());
+    }
+
+    #[test]
+    fn container_display() {
+        let attributes: Vec<Attribute> = parse_quote! { #[redefmt(display = "{x}:{y}")] };
+
+        let container_attributes = ContainerAttributes::parse(&attributes).unwrap();
+
+        assert_eq!("{x}:{y}", container_attributes.display.unwrap().value());
+    }
+
+    #[test]
+    fn container_type_names() {
+        let attributes: Vec<Attribute> = parse_quote! { #[redefmt(type_names)] };
+        assert!(ContainerAttributes::parse(&attributes).unwrap().type_names);
+
+        let attributes: Vec<Attribute> = parse_quote! { #[redefmt(display = "{x}")] };
+        assert!(!ContainerAttributes::parse(&attributes).unwrap().type_names);
+    }
+
+    #[test]
+    fn unsupported_attribute_error() {
+        let attributes: Vec<Attribute> = parse_quote! { #[redefmt(skip)] };
+
+        assert!(VariantAttributes::parse(&attributes).is_err());
+    }
+}
@@ -1,12 +1,15 @@
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote};
-use redefmt_db::{
-    Table,
-    statement_table::type_structure::{StructVariant, TypeStructure, TypeStructureVariant},
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, format_ident, quote};
+use redefmt_args::{
+    format_string::FormatString,
+    identifier::AnyIdentifier,
+    processor::{DynamicProcessorConfig, FormatProcessor, ProcessedFormatString},
 };
+use redefmt_db::statement_table::type_structure::{StructVariant, TypeStructure, TypeStructureVariant};
 use syn::{
-    Data, DataEnum, DataStruct, DeriveInput, Fields, Ident, TypeParamBound, parse_macro_input, spanned::Spanned,
+    Data, DataEnum, DataStruct, DeriveInput, Fields, Generics, Ident, LitStr, Member, TypeParamBound,
+    parse_macro_input, spanned::Spanned,
 };
 
 use crate::*;
@@ -18,55 +21,91 @@ pub fn macro_impl(token_stream: TokenStream) -> TokenStream {
 
     let ident = type_definition.ident;
 
-    let (variant, impl_body) = match type_definition.data {
+    let container_attributes = match ContainerAttributes::parse(&type_definition.attrs) {
+        Ok(container_attributes) => container_attributes,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    let derive_result = match type_definition.data {
         Data::Struct(data_struct) => struct_impl(data_struct),
         Data::Enum(data_enum) => enum_impl(&ident, data_enum),
-        Data::Union(data_union) => {
-            return syn::Error::new(
-                data_union.union_token.span,
-                "redefmt::Format derives on unions are not supported",
-            )
-            .into_compile_error()
-            .into();
-        }
+        Data::Union(data_union) => Err(syn::Error::new(
+            data_union.union_token.span,
+            "redefmt::Format derives on unions are not supported",
+        )),
+    };
+
+    let (variant, impl_body) = match derive_result {
+        Ok(derive_output) => derive_output,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    let display = match container_attributes
+        .display
+        .map(|lit| display_format_string(&lit, &variant))
+    {
+        Some(Ok(display)) => Some(display),
+        Some(Err(err)) => return err.into_compile_error().into(),
+        None => None,
+    };
+
+    let type_params = match container_attributes.type_names {
+        true => match type_names_params(&type_definition.generics) {
+            Ok(type_params) => type_params,
+            Err(err) => return err.into_compile_error().into(),
+        },
+        false => Vec::new(),
     };
 
-    let type_structure = TypeStructure { name: ident.to_string().into(), variant };
+    let type_structure = TypeStructure {
+        name: ident.to_string().into(),
+        module_path: ModulePath::of_span(&proc_macro::Span::call_site()).into(),
+        generics: type_params.iter().map(ToString::to_string).collect(),
+        variant,
+        display,
+    };
 
-    let id_pair = match db_clients.crate_db.insert(&type_structure) {
-        Ok(statement_id) => {
+    let (id_pair, embedded_statement) = match db_clients.insert(&type_structure) {
+        Ok((statement_id, embedded_statement)) => {
             let crate_id_inner = db_clients.crate_id.as_ref();
             let statement_id_inner = statement_id.as_ref();
             let formatter_ident = format_ident!("f");
 
-            ::quote::quote! {
+            let id_pair = ::quote::quote! {
                 #formatter_ident.write((
                     ::redefmt::identifiers::CrateId::new(#crate_id_inner),
                     ::redefmt::identifiers::TypeStructureId::new(#statement_id_inner)
                 ))
-            }
-        }
-        Err(err) => {
-            let macro_error = RedefmtMacroError::from(err);
-            return macro_error.as_compiler_error(ident.span());
+            };
+
+            (id_pair, embedded_statement)
         }
+        Err(err) => return err.as_compiler_error(ident.span()),
     };
 
     let mut generics = type_definition.generics;
     let (impl_generics, type_generics, where_clause) = {
-        let bounds: TypeParamBound = syn::parse_str("::redefmt::Format").expect("valid type parameter bound");
+        match container_attributes.bound {
+            Some(bound) => generics.make_where_clause().predicates.extend(bound),
+            None => {
+                let bounds: TypeParamBound = syn::parse_str("::redefmt::Format").expect("valid type parameter bound");
 
-        generics
-            .type_params_mut()
-            .for_each(|param| param.bounds.push(bounds.clone()));
+                generics
+                    .type_params_mut()
+                    .for_each(|param| param.bounds.push(bounds.clone()));
+            }
+        }
 
         generics.split_for_impl()
     };
 
     quote! {
+        #embedded_statement
+
         impl #impl_generics ::redefmt::Format for #ident #type_generics #where_clause {
             fn fmt(&self, f: &mut ::redefmt::Formatter) -> ::core::fmt::Result {
                 #id_pair;
+                #(f.write_raw(::core::any::type_name::<#type_params>());)*
                 #impl_body
                 ::core::fmt::Result::Ok(())
             }
@@ -75,89 +114,140 @@ pub fn macro_impl(token_stream: TokenStream) -> TokenStream {
     .into()
 }
 
-fn struct_impl(data_struct: DataStruct) -> (TypeStructureVariant, TokenStream2) {
-    let variant = struct_variant(&data_struct.fields);
+/// Type parameters whose names are dispatched with `redefmt(type_names)`
+///
+/// Const parameters are rejected rather than left out, as their values aren't
+/// known to `core::any::type_name`.
+fn type_names_params(generics: &Generics) -> syn::Result<Vec<Ident>> {
+    if let Some(const_param) = generics.const_params().next() {
+        return Err(syn::Error::new(
+            const_param.ident.span(),
+            "redefmt(type_names) does not support const generic parameters",
+        ));
+    }
+
+    Ok(generics
+        .type_params()
+        .map(|type_param| type_param.ident.clone())
+        .collect())
+}
+
+/// Struct fields are passed to the display format string positionally for
+/// tuple structs, and by their (possibly renamed) name for named structs.
+fn display_format_string(lit: &LitStr, variant: &TypeStructureVariant) -> syn::Result<ProcessedFormatString<'static>> {
+    let TypeStructureVariant::Struct(struct_variant) = variant else {
+        return Err(syn::Error::new(
+            lit.span(),
+            "redefmt(display) is only supported on structs",
+        ));
+    };
 
-    let impl_body = match data_struct.fields {
-        Fields::Unit => quote! {},
-        Fields::Named(fields_named) => {
-            let field_idents = fields_named
-                .named
-                .into_iter()
-                .map(|field| field.ident.expect("named field has no identifier"));
+    let (positional_count, named) = match struct_variant {
+        StructVariant::Unit => (0, Vec::new()),
+        StructVariant::Tuple(field_count) => (*field_count as usize, Vec::new()),
+        StructVariant::Named(field_names) => {
+            let named = field_names
+                .iter()
+                .filter_map(|field_name| AnyIdentifier::parse(field_name.clone()).ok())
+                .collect();
 
-            quote! { #(self.#field_idents.fmt(f)?;)* }
+            (0, named)
         }
-        Fields::Unnamed(fields_unnamed) => {
-            let tuple_indexes = (0..fields_unnamed.unnamed.len()).map(syn::Index::from);
+    };
 
-            quote! { #(self.#tuple_indexes.fmt(f)?;)* }
-        }
+    let processor_config = DynamicProcessorConfig {
+        disable_unused_named_check: true,
+        disable_unused_positional_check: true,
     };
 
-    (TypeStructureVariant::Struct(variant), impl_body)
+    let format_string = syn::parse2::<FormatString>(lit.to_token_stream())?;
+
+    FormatProcessor::process_dynamic(format_string, positional_count, &named, &processor_config)
+        .map_err(|err| syn::Error::new(lit.span(), err))
+}
+
+fn struct_impl(data_struct: DataStruct) -> syn::Result<(TypeStructureVariant, TokenStream2)> {
+    let derive_fields = DeriveField::collect(&data_struct.fields)?;
+    let variant = struct_variant(&data_struct.fields, &derive_fields)?;
+
+    let field_fmt_calls = derive_fields
+        .iter()
+        .filter(|field| !field.attributes.skip)
+        .map(|field| {
+            let member = &field.member;
+            field.fmt_call(quote! { self.#member }, quote! { &self.#member })
+        });
+
+    let impl_body = quote! { #(#field_fmt_calls)* };
+
+    Ok((TypeStructureVariant::Struct(variant), impl_body))
 }
 
-fn enum_impl(ident: &Ident, enum_struct: DataEnum) -> (TypeStructureVariant, TokenStream2) {
+fn enum_impl(ident: &Ident, enum_struct: DataEnum) -> syn::Result<(TypeStructureVariant, TokenStream2)> {
     // skip generating a match statemetn to avoid `error[E0004]: non-exhaustive patterns`
     if enum_struct.variants.is_empty() {
-        return (TypeStructureVariant::Enum(Vec::new()), quote! {});
+        return Ok((TypeStructureVariant::Enum(Vec::new()), quote! {}));
     }
 
-    let enum_variants = enum_struct
-        .variants
-        .iter()
-        .map(|variant| {
-            let name = variant.ident.to_string();
-            let struct_variant = struct_variant(&variant.fields);
-
-            (name, struct_variant)
-        })
-        .collect();
-
-    let match_arms = enum_struct
-        .variants
-        .into_iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_ident = variant.ident;
-
-            match variant.fields {
-                Fields::Named(fields_named) => {
-                    let field_idents = fields_named
-                        .named
-                        .into_iter()
-                        .map(|field| field.ident.expect("named field has no identifier"))
-                        .collect::<Vec<_>>();
-
-                    quote! {
-                        #ident::#variant_ident { #(#field_idents),* } => {
-                            f.write_raw(#variant_index);
-                            #(#field_idents.fmt(f)?;)*
-                        }
+    let mut enum_variants = Vec::with_capacity(enum_struct.variants.len());
+    let mut match_arms = Vec::with_capacity(enum_struct.variants.len());
+
+    for (variant_index, variant) in enum_struct.variants.into_iter().enumerate() {
+        let variant_attributes = VariantAttributes::parse(&variant.attrs)?;
+        let derive_fields = DeriveField::collect(&variant.fields)?;
+
+        let name = variant_attributes.rename.unwrap_or_else(|| variant.ident.to_string());
+        enum_variants.push((name, struct_variant(&variant.fields, &derive_fields)?));
+
+        let variant_ident = variant.ident;
+
+        let field_fmt_calls = derive_fields
+            .iter()
+            .filter(|field| !field.attributes.skip)
+            .map(|field| {
+                let binding = &field.binding;
+                field.fmt_call(quote! { #binding }, quote! { #binding })
+            })
+            .collect::<Vec<_>>();
+
+        let match_arm = match variant.fields {
+            Fields::Named(_) => {
+                let field_idents = derive_fields
+                    .iter()
+                    .filter(|field| !field.attributes.skip)
+                    .map(|field| &field.binding);
+
+                quote! {
+                    #ident::#variant_ident { #(#field_idents,)* .. } => {
+                        f.write_raw(#variant_index);
+                        #(#field_fmt_calls)*
                     }
                 }
-                Fields::Unnamed(fields_unnamed) => {
-                    let tuple_idents = (0..fields_unnamed.unnamed.len())
-                        .map(|index| format_ident!("i_{}", index))
-                        .collect::<Vec<_>>();
-
-                    quote! {
-                        #ident::#variant_ident(#(#tuple_idents),*) => {
-                            f.write_raw(#variant_index);
-                            #(#tuple_idents.fmt(f)?;)*
-                        }
+            }
+            Fields::Unnamed(_) => {
+                let tuple_patterns = derive_fields.iter().map(|field| match field.attributes.skip {
+                    true => quote! { _ },
+                    false => field.binding.to_token_stream(),
+                });
+
+                quote! {
+                    #ident::#variant_ident(#(#tuple_patterns),*) => {
+                        f.write_raw(#variant_index);
+                        #(#field_fmt_calls)*
                     }
                 }
-                Fields::Unit => {
-                    quote! {
-                        #ident::#variant_ident => {
-                            f.write_raw(#variant_index);
-                        }
+            }
+            Fields::Unit => {
+                quote! {
+                    #ident::#variant_ident => {
+                        f.write_raw(#variant_index);
                     }
                 }
             }
-        });
+        };
+
+        match_arms.push(match_arm);
+    }
 
     let impl_body = quote! {
         match self {
@@ -165,21 +255,95 @@ fn enum_impl(ident: &Ident, enum_struct: DataEnum) -> (TypeStructureVariant, Tok
         }
     };
 
-    (TypeStructureVariant::Enum(enum_variants), impl_body)
+    Ok((TypeStructureVariant::Enum(enum_variants), impl_body))
 }
 
-fn struct_variant(fields: &Fields) -> StructVariant {
-    match fields {
-        Fields::Named(fields_named) => {
-            let field_names = fields_named
-                .named
-                .iter()
-                .map(|field| field.ident.as_ref().expect("named field has no identifier").to_string())
+fn struct_variant(fields: &Fields, derive_fields: &[DeriveField]) -> syn::Result<StructVariant> {
+    let included_fields = derive_fields.iter().filter(|field| !field.attributes.skip);
+
+    let struct_variant = match fields {
+        Fields::Named(_) => {
+            let field_names = included_fields
+                .map(|field| match &field.attributes.rename {
+                    Some(rename) => rename.clone(),
+                    None => field.binding.to_string(),
+                })
                 .collect();
 
             StructVariant::Named(field_names)
         }
-        Fields::Unnamed(fields_unnamed) => StructVariant::Tuple(fields_unnamed.unnamed.len() as u8),
+        Fields::Unnamed(_) => {
+            if let Some(renamed_field) = derive_fields.iter().find(|field| field.attributes.rename.is_some()) {
+                return Err(syn::Error::new(
+                    renamed_field.span,
+                    "redefmt(rename) is only supported on named fields",
+                ));
+            }
+
+            StructVariant::Tuple(included_fields.count() as u8)
+        }
         Fields::Unit => StructVariant::Unit,
+    };
+
+    Ok(struct_variant)
+}
+
+struct DeriveField {
+    span: Span,
+    member: Member,
+    /// Field identifier if named, else `i_{index}`, used when destructuring enum variants
+    binding: Ident,
+    attributes: FieldAttributes,
+}
+
+impl DeriveField {
+    fn collect(fields: &Fields) -> syn::Result<Vec<Self>> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let (member, binding) = match &field.ident {
+                    Some(ident) => (Member::Named(ident.clone()), ident.clone()),
+                    None => (Member::Unnamed(index.into()), format_ident!("i_{}", index)),
+                };
+
+                let attributes = FieldAttributes::parse(&field.attrs)?;
+
+                Ok(Self { span: field.span(), member, binding, attributes })
+            })
+            .collect()
+    }
+
+    fn fmt_call(&self, value: TokenStream2, reference: TokenStream2) -> TokenStream2 {
+        match &self.attributes.with {
+            Some(with) => quote! { #with(#reference, f)?; },
+            None => quote! { #value.fmt(f)?; },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    #[test]
+    fn type_names_params_only_type_parameters() {
+        let generics: Generics = parse_quote! { <'a, T, U: Copy> };
+
+        let type_params = type_names_params(&generics).unwrap();
+
+        assert_eq!(
+            vec!["T", "U"],
+            type_params.iter().map(ToString::to_string).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn type_names_params_const_generic_error() {
+        let generics: Generics = parse_quote! { <T, const N: usize> };
+
+        assert!(type_names_params(&generics).is_err());
     }
 }
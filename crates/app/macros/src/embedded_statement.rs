@@ -0,0 +1,72 @@
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::quote;
+use redefmt_core::identifiers::CrateId;
+use redefmt_db::{
+    crate_table::Crate,
+    embedded::{EMBEDDED_SECTION_NAME, EmbeddedRecord},
+    statement_table::StatementTable,
+};
+
+use crate::*;
+
+pub struct EmbeddedStatement;
+
+impl EmbeddedStatement {
+    /// Static placed in the linker section read by `RedefmtDecoder::from_elf`
+    ///
+    /// Wrapped in an anonymous constant so that it may be expanded in both
+    /// item and expression position.
+    pub fn tokens<T: StatementTable>(
+        crate_id: CrateId,
+        krate: &Crate,
+        id: T::Id,
+        statement: &T,
+    ) -> Result<TokenStream2, RedefmtMacroError>
+    where
+        T::Id: AsRef<u16>,
+    {
+        let record = EmbeddedRecord::encode(crate_id, krate, id, statement)?;
+
+        let record_length = record.len();
+        let record_literal = Literal::byte_string(&record);
+
+        let tokens = quote! {
+            const _: () = {
+                #[used]
+                #[unsafe(link_section = #EMBEDDED_SECTION_NAME)]
+                static REDEFMT_EMBEDDED_STATEMENT: [u8; #record_length] = *#record_literal;
+            };
+        };
+
+        Ok(tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use redefmt_args::FormatExpression;
+    use redefmt_core::identifiers::WriteStatementId;
+    use redefmt_db::{crate_table::CrateName, statement_table::write::WriteStatement};
+    use syn::parse_quote;
+
+    use super::*;
+
+    #[test]
+    fn tokens() {
+        let format_expression: FormatExpression = parse_quote!("x");
+        let (stored_expression, _) = StatementUtils::dissolve_expression(format_expression, false).unwrap();
+        let statement = WriteStatement(stored_expression);
+
+        let krate = Crate::new(CrateName::new("x").unwrap());
+        let id = WriteStatementId::new(1);
+
+        let tokens = EmbeddedStatement::tokens(CrateId::new(1), &krate, id, &statement)
+            .unwrap()
+            .to_string();
+
+        let record = EmbeddedRecord::encode(CrateId::new(1), &krate, id, &statement).unwrap();
+
+        assert!(tokens.contains("link_section = \".redefmt\""));
+        assert!(tokens.contains(&format!("[u8 ; {}usize]", record.len())));
+    }
+}
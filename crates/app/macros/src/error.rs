@@ -2,18 +2,41 @@ use std::env::VarError;
 
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use redefmt_db::{DbClientError, StateDirError, crate_table::CrateNameError};
+use redefmt_db::{
+    DbClientError, StateDirError,
+    crate_table::{CrateNameError, CrateVersionError},
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum RedefmtMacroError {
     #[error("state directory resolution error")]
     StateDir(#[from] StateDirError),
     #[error("internal database failure")]
-    Db(#[from] DbClientError),
+    Db(#[source] DbClientError),
+    #[error("all statement IDs of '{0}' are taken, prune unreferenced statements with `redefmt gc`")]
+    IdOverflow(&'static str),
+    #[error("{0} of {1} bytes is too long to embed with the `embed` feature")]
+    EmbeddedFieldLength(&'static str, usize),
     #[error("failed to retrieve crate name from '$CARGO_PKG_NAME'")]
     CrateNameEnv(#[from] VarError),
     #[error("invalid crate name")]
     CrateName(#[from] CrateNameError),
+    #[error("failed to retrieve crate version from '$CARGO_PKG_VERSION'")]
+    CrateVersionEnv(#[source] VarError),
+    #[error("invalid crate version")]
+    CrateVersion(#[from] CrateVersionError),
+    #[error("argument '{0}' formatted with '{{:p}}' can't be formatted in any other way")]
+    MixedPointerArgument(String),
+}
+
+impl From<DbClientError> for RedefmtMacroError {
+    fn from(error: DbClientError) -> Self {
+        match error {
+            DbClientError::IdOverflow(table) => Self::IdOverflow(table),
+            DbClientError::EmbeddedFieldLength(field, length) => Self::EmbeddedFieldLength(field, length),
+            error => Self::Db(error),
+        }
+    }
 }
 
 impl RedefmtMacroError {
@@ -1,8 +1,5 @@
 //! # redefmt-macros
 
-// TEMP: should be part of next stable release (1.88)
-// https://github.com/rust-lang/rust/pull/140514
-#![feature(proc_macro_span)]
 // TEMP:
 #![allow(missing_docs)]
 
@@ -14,12 +11,26 @@ pub(crate) use error::RedefmtMacroError;
 mod db_client;
 pub(crate) use db_client::{DbClients, db_clients};
 
+mod embedded_statement;
+pub(crate) use embedded_statement::EmbeddedStatement;
+
 mod derive_format;
 
+mod derive_attributes;
+pub(crate) use derive_attributes::{ContainerAttributes, FieldAttributes, VariantAttributes};
+
 mod write_statement;
 
+mod crate_id;
+
 mod print_statement;
 
+mod module_path;
+pub(crate) use module_path::ModulePath;
+
+mod static_level_filter;
+pub(crate) use static_level_filter::StaticLevelFilter;
+
 mod statement_utils;
 pub(crate) use statement_utils::StatementUtils;
 
@@ -28,6 +39,13 @@ pub fn derive_format(token_stream: TokenStream) -> TokenStream {
     derive_format::macro_impl(token_stream)
 }
 
+/// `CrateId` the statement database assigned to the calling crate, ex. for
+/// `GlobalLogger::set_crate_max_level`
+#[proc_macro]
+pub fn crate_id(token_stream: TokenStream) -> TokenStream {
+    crate_id::macro_impl(token_stream)
+}
+
 #[proc_macro]
 pub fn write(token_stream: TokenStream) -> TokenStream {
     write_statement::macro_impl(token_stream, false)
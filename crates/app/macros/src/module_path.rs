@@ -0,0 +1,94 @@
+use std::path::{Path, PathBuf};
+
+pub struct ModulePath;
+
+impl ModulePath {
+    /// Module path of the file containing the given span, see [`Self::resolve`]
+    pub fn of_span(rust_span: &proc_macro::Span) -> String {
+        // Set by cargo for the crate being compiled
+        let crate_name = std::env::var("CARGO_CRATE_NAME").unwrap_or_default();
+        let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_default();
+
+        let Some(file) = rust_span.local_file() else {
+            return crate_name;
+        };
+
+        // Relative to the compiler working directory, usually the workspace root
+        let file = std::env::current_dir().map(|dir| dir.join(&file)).unwrap_or(file);
+
+        Self::resolve(&crate_name, &manifest_dir, &file)
+    }
+
+    /// Approximate `module_path!()` of the call site from its source file
+    ///
+    /// Proc macros have no way of querying the module path directly, it's
+    /// instead inferred from the file path relative to the crate manifest
+    /// directory, following the cargo target layout conventions. Inline
+    /// modules and `#[path]` attributes are therefore not accounted for.
+    pub fn resolve(crate_name: &str, manifest_dir: &Path, file: &Path) -> String {
+        let mut module_path = crate_name.to_string();
+
+        let file = file.with_extension("");
+
+        let Ok(relative_file) = file.strip_prefix(manifest_dir) else {
+            return module_path;
+        };
+
+        let components = relative_file
+            .iter()
+            .map(|component| component.to_str())
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default();
+
+        let mut modules = match components.as_slice() {
+            ["src", "bin", _, modules @ ..] => modules,
+            ["src", modules @ ..] => modules,
+            ["tests" | "examples" | "benches", _, modules @ ..] => modules,
+            _ => return module_path,
+        };
+
+        // crate roots and `mod.rs` files
+        if let [] | ["lib"] | ["main"] = modules {
+            modules = &[];
+        } else if let [parent_modules @ .., "mod"] = modules {
+            modules = parent_modules;
+        }
+
+        for module in modules {
+            module_path.push_str("::");
+            module_path.push_str(module);
+        }
+
+        module_path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve() {
+        assert_resolved("src/lib.rs", "x");
+        assert_resolved("src/main.rs", "x");
+        assert_resolved("src/foo.rs", "x::foo");
+        assert_resolved("src/foo/mod.rs", "x::foo");
+        assert_resolved("src/foo/bar.rs", "x::foo::bar");
+        assert_resolved("src/bin/y.rs", "x");
+        assert_resolved("src/bin/y/main.rs", "x");
+        assert_resolved("src/bin/y/foo.rs", "x::foo");
+        assert_resolved("tests/y.rs", "x");
+        assert_resolved("tests/y/foo.rs", "x::foo");
+        assert_resolved("build.rs", "x");
+        assert_resolved("/elsewhere/src/foo.rs", "x");
+    }
+
+    fn assert_resolved(file: &str, expected: &str) {
+        let manifest_dir = Path::new("/crate");
+        let file = manifest_dir.join(file);
+
+        assert_eq!(expected, ModulePath::resolve("x", manifest_dir, &file));
+    }
+}
@@ -3,16 +3,18 @@ use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
 use redefmt_args::FormatExpression;
 use redefmt_core::frame::Level;
-use redefmt_db::{
-    Table,
-    statement_table::print::{Location, PrintStatement},
-};
-use syn::{Token, parse::ParseStream, parse_macro_input};
+use redefmt_db::statement_table::print::{Location, PrintStatement};
+use syn::{Token, parse::ParseStream, parse_macro_input, parse_quote};
 
 use crate::*;
 
+mod kw {
+    syn::custom_keyword!(target);
+}
+
 struct Args {
     span: Span,
+    target: Option<syn::LitStr>,
     level_expression: Option<syn::Expr>,
     format_expression: FormatExpression<'static>,
     compat_args_expression: Option<TokenStream2>,
@@ -48,9 +50,17 @@ impl Args {
 
     fn parse_impl(with_level: bool, fork_for_compat: bool, input: ParseStream) -> syn::Result<Self> {
         let span = input.span();
+        let mut target = None;
         let mut level_expression = None;
         let mut compat_args_expression = None;
 
+        if input.peek(kw::target) && input.peek2(Token![:]) {
+            let _ = input.parse::<kw::target>()?;
+            let _ = input.parse::<Token![:]>()?;
+            target = input.parse().map(Some)?;
+            let _ = input.parse::<Token![,]>()?;
+        }
+
         if with_level {
             level_expression = input.parse().map(Some)?;
             let _ = input.parse::<Token![,]>()?;
@@ -62,12 +72,25 @@ impl Args {
 
         let format_expression = input.parse()?;
 
-        Ok(Self { span, level_expression, format_expression, compat_args_expression })
+        Ok(Self {
+            span,
+            target,
+            level_expression,
+            format_expression,
+            compat_args_expression,
+        })
     }
 }
 
 pub fn print_macro_impl(token_stream: TokenStream, add_non_deferred: bool, append_newline: bool) -> TokenStream {
     let args = parse_args!(token_stream, add_non_deferred, false);
+
+    if let Some(target) = &args.target {
+        return syn::Error::new(target.span(), "targets are only supported by log statements")
+            .into_compile_error()
+            .into();
+    }
+
     try_macro_impl(args, append_newline)
 }
 
@@ -83,8 +106,28 @@ pub fn shorthand_log_macro_impl(token_stream: TokenStream, add_non_deferred: boo
 }
 
 fn try_macro_impl(args: Args, append_newline: bool) -> TokenStream {
-    let Args { span, level_expression, format_expression, compat_args_expression } = args;
+    let Args {
+        span,
+        target,
+        level_expression,
+        format_expression,
+        compat_args_expression,
+    } = args;
+
+    let disabled_level = level_expression
+        .as_ref()
+        .and_then(StaticLevelFilter::static_level)
+        .is_some_and(|level| !StaticLevelFilter::enabled(level));
+
+    if disabled_level {
+        return match disabled_macro_impl(format_expression) {
+            Ok(tokens) => tokens.into(),
+            Err(err) => err.as_compiler_error(span),
+        };
+    }
+
     match macro_impl(
+        target,
         level_expression,
         format_expression,
         compat_args_expression,
@@ -97,6 +140,7 @@ fn try_macro_impl(args: Args, append_newline: bool) -> TokenStream {
 
 /// `level_expression` = None implies print statement
 fn macro_impl(
+    target: Option<syn::LitStr>,
     level_expression: Option<syn::Expr>,
     format_expression: FormatExpression,
     compat_args_expression: Option<TokenStream2>,
@@ -104,11 +148,15 @@ fn macro_impl(
 ) -> Result<TokenStream2, RedefmtMacroError> {
     let db_clients = DbClients::new()?;
 
-    let (stored_expression, provided_args) = StatementUtils::dissolve_expression(format_expression, append_newline);
+    let (stored_expression, provided_args) = StatementUtils::dissolve_expression(format_expression, append_newline)?;
 
-    let print_statement = PrintStatement { location: location(), stored_expression };
+    let print_statement = PrintStatement {
+        location: location(),
+        target: target.as_ref().map(|target| target.value().into()),
+        stored_expression,
+    };
 
-    let statement_id = db_clients.crate_db.insert(&print_statement)?;
+    let (statement_id, embedded_statement) = db_clients.insert(&print_statement)?;
 
     let crate_id_inner = db_clients.crate_id.as_ref();
     let statement_id_inner = statement_id.as_ref();
@@ -120,8 +168,10 @@ fn macro_impl(
 
     let non_deferred_expr = match (&level_expression, compat_args_expression) {
         (Some(level_expression), Some(log_args)) => {
+            let log_target = target.map(|target| quote! { target: #target, });
+
             let log_compat_expr = quote! {
-                ::redefmt::redefmt_to_log!(::redefmt::LevelCompat(#level_expression).into(), #log_args)
+                ::redefmt::redefmt_to_log!(#log_target ::redefmt::LevelCompat(#level_expression).into(), #log_args)
             };
 
             Some(log_compat_expr)
@@ -138,17 +188,20 @@ fn macro_impl(
     };
 
     let deferred_expr = quote! {
-        let mut global_logger_handle = ::redefmt::logger::GlobalLogger::write_start(
+        #embedded_statement
+
+        if let Some(mut global_logger_handle) = ::redefmt::logger::GlobalLogger::write_start(
             (
                 ::redefmt::identifiers::CrateId::new(#crate_id_inner),
                 ::redefmt::identifiers::PrintStatementId::new(#statement_id_inner)
             ),
             #maybe_log_level
-        );
-        #(
-            global_logger_handle.write_format(&(&#provided_args));
-        )*
-        global_logger_handle.write_end();
+        ) {
+            #(
+                global_logger_handle.write_format(&(&#provided_args));
+            )*
+            global_logger_handle.write_end();
+        }
     };
 
     Ok(quote! {
@@ -157,21 +210,60 @@ fn macro_impl(
     })
 }
 
+// Skips both the statement registration and any writes. Provided arguments
+// are nevertheless referenced in dead code so that variables only used by
+// disabled statements don't trigger unused warnings.
+fn disabled_macro_impl(format_expression: FormatExpression) -> Result<TokenStream2, RedefmtMacroError> {
+    let (_, provided_args) = StatementUtils::dissolve_expression(format_expression, false)?;
+
+    let tokens = quote! {
+        if false {
+            #(
+                let _ = &(#provided_args);
+            )*
+        }
+    };
+
+    Ok(tokens)
+}
+
 fn location() -> Location<'static> {
     let rust_span = proc_macro::Span::call_site();
     let file = rust_span.file().into();
-    let line = rust_span.start().line() as u32;
-    Location { file, line }
+    let (start, end) = (rust_span.start(), rust_span.end());
+    let module_path = ModulePath::of_span(&rust_span).into();
+
+    Location {
+        file,
+        line: start.line() as u32,
+        column: start.column() as u32,
+        end_line: end.line() as u32,
+        end_column: end.column() as u32,
+        module_path,
+    }
 }
 
 fn level_expression(level: Level) -> syn::Expr {
-    let tokens = match level {
-        Level::Trace => quote! { ::redefmt::Level::Trace },
-        Level::Debug => quote! { ::redefmt::Level::Debug },
-        Level::Info => quote! { ::redefmt::Level::Info },
-        Level::Warn => quote! { ::redefmt::Level::Warn },
-        Level::Error => quote! { ::redefmt::Level::Error },
-    };
+    match level {
+        Level::Trace => parse_quote! { ::redefmt::Level::Trace },
+        Level::Debug => parse_quote! { ::redefmt::Level::Debug },
+        Level::Info => parse_quote! { ::redefmt::Level::Info },
+        Level::Warn => parse_quote! { ::redefmt::Level::Warn },
+        Level::Error => parse_quote! { ::redefmt::Level::Error },
+    }
+}
 
-    syn::Expr::Verbatim(tokens)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_expansion() {
+        let format_expression: FormatExpression = parse_quote!("{} {x:?}", y + 1);
+
+        let tokens = disabled_macro_impl(format_expression).unwrap();
+
+        // arguments are type checked but never evaluated, nor is the statement registered
+        assert_eq!("if false { let _ = & (y + 1) ; let _ = & (& x) ; }", tokens.to_string());
+    }
 }
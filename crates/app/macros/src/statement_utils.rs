@@ -1,5 +1,13 @@
-use redefmt_args::FormatExpression;
+use redefmt_args::{
+    FormatExpression,
+    format_string::{argument::FormatArgument, options::FormatTrait},
+    processor::ProcessedFormatString,
+};
+use redefmt_core::frame::TypeHint;
 use redefmt_db::statement_table::stored_format_expression::StoredFormatExpression;
+use syn::parse_quote;
+
+use crate::*;
 
 pub struct StatementUtils;
 
@@ -7,27 +15,276 @@ impl StatementUtils {
     pub fn dissolve_expression<'a>(
         format_expression: FormatExpression<'a>,
         append_newline: bool,
-    ) -> (StoredFormatExpression<'a>, Vec<syn::Expr>) {
+    ) -> Result<(StoredFormatExpression<'a>, Vec<syn::Expr>), RedefmtMacroError> {
         let FormatExpression { processed_format_string, provided_args } = format_expression;
 
-        let mut provided_expressions = provided_args.positional.into_iter().collect::<Vec<_>>();
+        let mut provided_expressions = Vec::new();
+        let mut provided_arg_hints = Vec::new();
+
+        for (index, expr) in provided_args.positional.into_iter().enumerate() {
+            let argument = FormatArgument::Index(index);
+            let (expr, type_hint) = Self::provided_expression(&processed_format_string, &argument, expr)?;
+            provided_expressions.push(expr);
+            provided_arg_hints.push(type_hint);
+        }
+
+        let mut provided_identifiers = Vec::new();
+
+        for (identifier, expr) in provided_args.named {
+            let argument = identifier.clone().into();
+            let (expr, type_hint) = Self::provided_expression(&processed_format_string, &argument, expr)?;
+            provided_expressions.push(expr);
+            provided_arg_hints.push(type_hint);
+            provided_identifiers.push(identifier);
+        }
 
-        let provided_identifiers = provided_args
-            .named
-            .into_iter()
-            .map(|(identifier, expr)| {
-                provided_expressions.push(expr);
-                identifier
-            })
-            .collect::<Vec<_>>();
+        // Keeps statements without any statically known argument type
+        // identical to those registered before hints were elided.
+        if provided_arg_hints.iter().all(Option::is_none) {
+            provided_arg_hints.clear();
+        }
 
         let stored_expression = StoredFormatExpression {
             format_string: processed_format_string,
             append_newline,
             expected_positional_arg_count: provided_expressions.len() - provided_identifiers.len(),
             expected_named_args: provided_identifiers,
+            provided_arg_hints,
+        };
+
+        Ok((stored_expression, provided_expressions))
+    }
+
+    // Arguments with a statically known type are wrapped in a `RawValue`, and
+    // their type hint stored with the statement rather than written each time.
+    fn provided_expression(
+        processed_format_string: &ProcessedFormatString,
+        argument: &FormatArgument,
+        expr: syn::Expr,
+    ) -> Result<(syn::Expr, Option<TypeHint>), RedefmtMacroError> {
+        let (expr, type_hint) = match Self::pointer_expression(processed_format_string, argument, &expr)? {
+            Some(pointer_expr) => (pointer_expr, Some(TypeHint::Pointer)),
+            None => {
+                let type_hint = static_type_hint(&expr);
+                (expr, type_hint)
+            }
         };
 
-        (stored_expression, provided_expressions)
+        let Some(type_hint) = type_hint else {
+            return Ok((expr, None));
+        };
+
+        let hinted_type = hinted_type(type_hint);
+        let raw_expr = parse_quote! { ::redefmt::RawValue::<#hinted_type>(#expr) };
+
+        Ok((raw_expr, Some(type_hint)))
+    }
+
+    // Arguments formatted with `{:p}` are written as the address they point
+    // to, rather than as the value they point to.
+    fn pointer_expression(
+        processed_format_string: &ProcessedFormatString,
+        argument: &FormatArgument,
+        expr: &syn::Expr,
+    ) -> Result<Option<syn::Expr>, RedefmtMacroError> {
+        let usages = processed_format_string.argument_usages(argument);
+
+        if !usages.contains(&Some(FormatTrait::Pointer)) {
+            return Ok(None);
+        }
+
+        if usages.iter().any(|usage| *usage != Some(FormatTrait::Pointer)) {
+            let argument_name = match argument {
+                FormatArgument::Index(index) => index.to_string(),
+                FormatArgument::Identifier(identifier) => identifier.to_string(),
+            };
+
+            return Err(RedefmtMacroError::MixedPointerArgument(argument_name));
+        }
+
+        let pointer_expr = match expr {
+            syn::Expr::Reference(reference) if reference.mutability.is_none() && is_identifier(&reference.expr) => {
+                parse_quote! {
+                    {
+                        use ::redefmt::{ReferencedPointee as _, ReferencedPointer as _};
+                        (&::redefmt::ReferencedArgument(#expr)).as_pointer()
+                    }
+                }
+            }
+            _ => parse_quote! { ::redefmt::FormatPointer::as_pointer(&(#expr)) },
+        };
+
+        Ok(Some(pointer_expr))
+    }
+}
+
+fn is_identifier(expr: &syn::Expr) -> bool {
+    matches!(expr, syn::Expr::Path(path) if path.path.get_ident().is_some())
+}
+
+const PRIMITIVES: [(&str, TypeHint); 16] = [
+    ("bool", TypeHint::Boolean),
+    ("char", TypeHint::Char),
+    ("usize", TypeHint::Usize),
+    ("u8", TypeHint::U8),
+    ("u16", TypeHint::U16),
+    ("u32", TypeHint::U32),
+    ("u64", TypeHint::U64),
+    ("u128", TypeHint::U128),
+    ("isize", TypeHint::Isize),
+    ("i8", TypeHint::I8),
+    ("i16", TypeHint::I16),
+    ("i32", TypeHint::I32),
+    ("i64", TypeHint::I64),
+    ("i128", TypeHint::I128),
+    ("f32", TypeHint::F32),
+    ("f64", TypeHint::F64),
+];
+
+fn primitive_type_hint(name: &str) -> Option<TypeHint> {
+    PRIMITIVES
+        .iter()
+        .find(|(primitive, _)| *primitive == name)
+        .map(|(_, type_hint)| *type_hint)
+}
+
+// Only literals and casts to primitives, the type of any other expression
+// being unknown to proc macros.
+fn static_type_hint(expr: &syn::Expr) -> Option<TypeHint> {
+    match expr {
+        syn::Expr::Lit(expr_lit) => match &expr_lit.lit {
+            // Unsuffixed literals fall back to `i32` and `f64`, like they
+            // would when passed to `Format::fmt` as is.
+            syn::Lit::Int(lit) => match lit.suffix() {
+                "" => Some(TypeHint::I32),
+                suffix => primitive_type_hint(suffix),
+            },
+            syn::Lit::Float(lit) => match lit.suffix() {
+                "" => Some(TypeHint::F64),
+                suffix => primitive_type_hint(suffix),
+            },
+            syn::Lit::Bool(_) => Some(TypeHint::Boolean),
+            syn::Lit::Char(_) => Some(TypeHint::Char),
+            syn::Lit::Byte(_) => Some(TypeHint::U8),
+            syn::Lit::Str(_) => Some(TypeHint::StringSlice),
+            _ => None,
+        },
+        syn::Expr::Unary(expr_unary) if matches!(expr_unary.op, syn::UnOp::Neg(_)) => {
+            static_type_hint(&expr_unary.expr)
+        }
+        syn::Expr::Paren(expr_paren) => static_type_hint(&expr_paren.expr),
+        syn::Expr::Group(expr_group) => static_type_hint(&expr_group.expr),
+        syn::Expr::Cast(expr_cast) => match &*expr_cast.ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => type_path
+                .path
+                .get_ident()
+                .and_then(|ident| primitive_type_hint(&ident.to_string())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn hinted_type(type_hint: TypeHint) -> syn::Type {
+    match type_hint {
+        TypeHint::StringSlice => parse_quote! { &str },
+        TypeHint::Pointer => parse_quote! { *const () },
+        _ => {
+            let (primitive, _) = PRIMITIVES
+                .iter()
+                .find(|(_, primitive_type_hint)| *primitive_type_hint == type_hint)
+                .expect("type hint of a primitive");
+
+            syn::parse_str(primitive).expect("valid primitive type")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+
+    use super::*;
+
+    #[test]
+    fn pointer_arguments() {
+        let format_expression: FormatExpression = parse_quote!("{:p} {x:p} {}", &y, z);
+
+        let (_, provided_expressions) = StatementUtils::dissolve_expression(format_expression, false).unwrap();
+
+        let [referenced, value, captured] = provided_expressions.as_slice() else {
+            panic!("expected three provided expressions");
+        };
+
+        assert!(referenced.to_token_stream().to_string().contains("ReferencedArgument"));
+        assert_eq!("& z", value.to_token_stream().to_string());
+        assert!(captured.to_token_stream().to_string().contains("ReferencedArgument"));
+    }
+
+    #[test]
+    fn static_type_hints() {
+        let format_expression: FormatExpression =
+            parse_quote!("{} {} {} {} {:p} {named}", 1, -2.5f32, x as u16, y, &z, named = "a");
+
+        let (stored_expression, provided_expressions) =
+            StatementUtils::dissolve_expression(format_expression, false).unwrap();
+
+        let expected_hints = [
+            Some(TypeHint::I32),
+            Some(TypeHint::F32),
+            Some(TypeHint::U16),
+            None,
+            Some(TypeHint::Pointer),
+            Some(TypeHint::StringSlice),
+        ];
+
+        assert_eq!(expected_hints.as_slice(), stored_expression.provided_arg_hints);
+
+        let cast = provided_expressions[2].to_token_stream().to_string();
+        assert_eq!(":: redefmt :: RawValue :: < u16 > (x as u16)", cast);
+
+        assert_eq!("& y", provided_expressions[3].to_token_stream().to_string());
+    }
+
+    #[test]
+    fn named_and_captured_type_hints() {
+        let format_expression: FormatExpression = parse_quote!("{x} {} {named} {y}", true, named = 2u16);
+
+        let (stored_expression, provided_expressions) =
+            StatementUtils::dissolve_expression(format_expression, false).unwrap();
+
+        let expected_named_args = ["named", "x", "y"];
+        assert_eq!(
+            expected_named_args.as_slice(),
+            stored_expression
+                .expected_named_args
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+
+        // positional arguments first, then named ones in the order above
+        let expected_hints = [Some(TypeHint::Boolean), Some(TypeHint::U16), None, None];
+        assert_eq!(expected_hints.as_slice(), stored_expression.provided_arg_hints);
+
+        assert_eq!("& x", provided_expressions[2].to_token_stream().to_string());
+    }
+
+    #[test]
+    fn no_static_type_hints() {
+        let format_expression: FormatExpression = parse_quote!("{} {x}", y);
+
+        let (stored_expression, _) = StatementUtils::dissolve_expression(format_expression, false).unwrap();
+
+        assert!(stored_expression.provided_arg_hints.is_empty());
+    }
+
+    #[test]
+    fn mixed_pointer_argument_error() {
+        let format_expression: FormatExpression = parse_quote!("{0:p} {0}", x);
+
+        let error = StatementUtils::dissolve_expression(format_expression, false).unwrap_err();
+
+        assert!(matches!(error, RedefmtMacroError::MixedPointerArgument(argument) if argument == "0"));
     }
 }
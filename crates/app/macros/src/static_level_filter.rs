@@ -0,0 +1,109 @@
+use redefmt_core::frame::{Level, LevelFilter};
+
+// Set by the `max-level-*` features, checked in order of restrictiveness so
+// that the most restrictive one wins.
+const MAX_LEVEL: LevelFilter = if cfg!(feature = "max-level-off") {
+    LevelFilter::Off
+} else if cfg!(feature = "max-level-error") {
+    LevelFilter::Error
+} else if cfg!(feature = "max-level-warn") {
+    LevelFilter::Warn
+} else if cfg!(feature = "max-level-info") {
+    LevelFilter::Info
+} else if cfg!(feature = "max-level-debug") {
+    LevelFilter::Debug
+} else {
+    LevelFilter::Trace
+};
+
+pub struct StaticLevelFilter;
+
+impl StaticLevelFilter {
+    pub fn enabled(level: Level) -> bool {
+        MAX_LEVEL.enables(level)
+    }
+
+    /// Level of expressions such as `Level::Info` or `::redefmt::Level::Info`
+    ///
+    /// Returns `None` for any other expression, as their level is only known at runtime.
+    pub fn static_level(level_expression: &syn::Expr) -> Option<Level> {
+        let syn::Expr::Path(expr_path) = level_expression else {
+            return None;
+        };
+
+        let mut segments = expr_path.path.segments.iter().rev();
+
+        let variant = segments.next()?;
+        let enum_name = segments.next()?;
+
+        if enum_name.ident != "Level" || !variant.arguments.is_none() {
+            return None;
+        }
+
+        let level = match variant.ident.to_string().as_str() {
+            "Trace" => Level::Trace,
+            "Debug" => Level::Debug,
+            "Info" => Level::Info,
+            "Warn" => Level::Warn,
+            "Error" => Level::Error,
+            _ => return None,
+        };
+
+        Some(level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    const LEVELS: [Level; 5] = [Level::Trace, Level::Debug, Level::Info, Level::Warn, Level::Error];
+
+    #[test]
+    fn static_level() {
+        assert_static_level(parse_quote!(Level::Info), Some(Level::Info));
+        assert_static_level(parse_quote!(redefmt::Level::Warn), Some(Level::Warn));
+        assert_static_level(parse_quote!(::redefmt::Level::Error), Some(Level::Error));
+
+        // runtime expressions
+        assert_static_level(parse_quote!(level), None);
+        assert_static_level(parse_quote!(Trace), None);
+        assert_static_level(parse_quote!(levels[0]), None);
+
+        // other enums and variants
+        assert_static_level(parse_quote!(LogLevel::Info), None);
+        assert_static_level(parse_quote!(Level::Verbose), None);
+        assert_static_level(parse_quote!(Level::Info::<u8>), None);
+
+        fn assert_static_level(expression: syn::Expr, expected: Option<Level>) {
+            assert_eq!(expected, StaticLevelFilter::static_level(&expression));
+        }
+    }
+
+    #[test]
+    fn enabled_by_max_level() {
+        for level in LEVELS {
+            assert_eq!(MAX_LEVEL.enables(level), StaticLevelFilter::enabled(level));
+        }
+    }
+
+    #[test]
+    #[cfg(not(any(
+        feature = "max-level-off",
+        feature = "max-level-error",
+        feature = "max-level-warn",
+        feature = "max-level-info",
+        feature = "max-level-debug"
+    )))]
+    fn all_enabled_by_default() {
+        assert!(LEVELS.into_iter().all(StaticLevelFilter::enabled));
+    }
+
+    #[test]
+    #[cfg(feature = "max-level-off")]
+    fn most_restrictive_wins() {
+        assert!(!LEVELS.into_iter().any(StaticLevelFilter::enabled));
+    }
+}
@@ -2,7 +2,7 @@ use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
 use redefmt_args::FormatExpression;
-use redefmt_db::{Table, statement_table::write::WriteStatement};
+use redefmt_db::statement_table::write::WriteStatement;
 use syn::{Token, parse_macro_input};
 
 use crate::*;
@@ -12,16 +12,17 @@ pub fn macro_impl(token_stream: TokenStream, append_newline: bool) -> TokenStrea
 
     let db_clients = db_clients!(span);
 
-    let (stored_expression, provided_args) = StatementUtils::dissolve_expression(format_expression, append_newline);
+    let (stored_expression, provided_args) =
+        match StatementUtils::dissolve_expression(format_expression, append_newline) {
+            Ok(dissolved_expression) => dissolved_expression,
+            Err(err) => return err.as_compiler_error(span),
+        };
 
     let write_statement = WriteStatement(stored_expression);
 
-    let statement_id = match db_clients.crate_db.insert(&write_statement) {
-        Ok(statement_id) => statement_id,
-        Err(err) => {
-            let macro_error = RedefmtMacroError::from(err);
-            return macro_error.as_compiler_error(span);
-        }
+    let (statement_id, embedded_statement) = match db_clients.insert(&write_statement) {
+        Ok(registered_statement) => registered_statement,
+        Err(err) => return err.as_compiler_error(span),
     };
 
     let crate_id_inner = db_clients.crate_id.as_ref();
@@ -31,6 +32,8 @@ pub fn macro_impl(token_stream: TokenStream, append_newline: bool) -> TokenStrea
         {
             use ::redefmt::Format as _;
 
+            #embedded_statement
+
             #statement_writer_ident.write_statement_id(
                 ::redefmt::identifiers::CrateId::new(#crate_id_inner),
                 ::redefmt::identifiers::WriteStatementId::new(#statement_id_inner)
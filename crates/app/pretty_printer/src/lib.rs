@@ -12,8 +12,8 @@ mod printer {
     use chrono::{DateTime, Utc};
     use redefmt_args::{
         deferred::{
-            DeferredFormatConfig, DeferredFormatError, DeferredStructVariant, DeferredTypeValue, DeferredTypeVariant,
-            DeferredValue, DeferredValues,
+            DeferredFormatConfig, DeferredFormatError, DeferredPointerValue, DeferredStructVariant, DeferredTypeValue,
+            DeferredTypeVariant, DeferredValue, DeferredValues,
         },
         identifier::AnyIdentifier,
         processor::ProcessedFormatString,
@@ -28,36 +28,68 @@ mod printer {
 
     use crate::*;
 
-    const FORMAT_DEFERRED_CONFIG: DeferredFormatConfig = DeferredFormatConfig {
+    const FORMAT_DEFERRED_CONFIG: DeferredFormatConfig<'static> = DeferredFormatConfig {
         allow_non_usize_precision_value: true,
         allow_non_usize_width_value: true,
+        full_type_names: false,
+        list_separator: None,
+        bytes_limit: None,
     };
 
     pub struct PrettyPrinter {
         first_frame_start: Option<DateTime<Utc>>,
+        last_sequence: Option<u16>,
         config: PrettyPrinterConfig,
+        /// Without the list separator, borrowed from `config` for each frame
+        deferred_format_config: DeferredFormatConfig<'static>,
     }
 
     impl PrettyPrinter {
         pub fn new(config: PrettyPrinterConfig) -> Self {
-            Self { first_frame_start: None, config }
+            let deferred_format_config = DeferredFormatConfig {
+                full_type_names: config.full_type_names,
+                bytes_limit: config.bytes_limit,
+                ..FORMAT_DEFERRED_CONFIG
+            };
+
+            Self {
+                first_frame_start: None,
+                last_sequence: None,
+                config,
+                deferred_format_config,
+            }
         }
 
         pub fn format(&mut self, redefmt_frame: RedefmtFrame) -> Result<String, DeferredFormatError> {
             let RedefmtFrame {
                 level,
+                sequence,
                 stamp,
                 crate_name,
+                module_path,
+                target,
                 file_name,
                 file_line,
+                file_column,
+                file_end_line: _,
+                file_end_column: _,
                 format_string,
                 append_newline,
                 decoded_values,
+                dropped_bytes: _,
             } = redefmt_frame;
 
             let stamp = stamp.map(|stamp| self.evaluate_stamp(stamp)).unwrap_or_default();
 
-            let statement = Self::evaluate_statement(format_string, &decoded_values, append_newline)?;
+            let statement = Self::evaluate_statement(
+                format_string,
+                &decoded_values,
+                append_newline,
+                &DeferredFormatConfig {
+                    list_separator: self.config.list_separator.as_deref(),
+                    ..self.deferred_format_config
+                },
+            )?;
 
             // SAFETY: `AnyIdentifier`s constructed with are valid identifier strings
             let named_values = unsafe {
@@ -86,14 +118,46 @@ mod printer {
                         AnyIdentifier::new_unchecked(false, "statement"),
                         DeferredValue::String(statement.into()),
                     ),
+                    (
+                        AnyIdentifier::new_unchecked(false, "module"),
+                        DeferredValue::String(module_path.into()),
+                    ),
+                    (
+                        AnyIdentifier::new_unchecked(false, "target"),
+                        // Defaults to module path, like `log` does
+                        DeferredValue::String(target.unwrap_or(module_path).into()),
+                    ),
+                    (
+                        AnyIdentifier::new_unchecked(false, "column"),
+                        DeferredValue::U32(file_column),
+                    ),
                 ]
             };
 
             let deferred_values = DeferredValues::new([], named_values);
 
-            self.config
+            let log_string = self
+                .config
                 .log_format_string
-                .format_deferred(&deferred_values, &FORMAT_DEFERRED_CONFIG)
+                .format_deferred(&deferred_values, &FORMAT_DEFERRED_CONFIG)?;
+
+            match sequence.and_then(|sequence| self.evaluate_lost_frames(sequence)) {
+                Some(lost_frames) => Ok(format!("{lost_frames} frames lost\n{log_string}")),
+                None => Ok(log_string),
+            }
+        }
+
+        fn evaluate_lost_frames(&mut self, sequence: u16) -> Option<u16> {
+            let last_sequence = self.last_sequence.replace(sequence)?;
+
+            // A wrap only ever continues from `u16::MAX`, the target has
+            // otherwise restarted its sequence, ex. after a reboot.
+            if sequence == 0 && last_sequence != u16::MAX {
+                return None;
+            }
+
+            let lost_frames = sequence.wrapping_sub(last_sequence).wrapping_sub(1);
+            (lost_frames != 0).then_some(lost_frames)
         }
 
         fn evaluate_stamp(&mut self, stamp: u64) -> String {
@@ -134,9 +198,10 @@ mod printer {
             format_string: &ProcessedFormatString,
             decoded_values: &DecodedValues,
             append_newline: bool,
+            config: &DeferredFormatConfig,
         ) -> Result<String, DeferredFormatError> {
-            let deferred_values = convert_decoded_values(decoded_values)?;
-            let mut expression_string = format_string.format_deferred(&deferred_values, &FORMAT_DEFERRED_CONFIG)?;
+            let deferred_values = convert_decoded_values(decoded_values, config)?;
+            let mut expression_string = format_string.format_deferred(&deferred_values, config)?;
 
             if append_newline {
                 expression_string.push('\n');
@@ -146,23 +211,33 @@ mod printer {
 
             fn convert_decoded_values<'v>(
                 decoded_values: &'v DecodedValues,
+                config: &DeferredFormatConfig,
             ) -> Result<DeferredValues<'v>, DeferredFormatError> {
                 let DecodedValues { positional, named } = decoded_values;
 
-                let deferred_positional = convert_values(positional)?;
+                let deferred_positional = convert_values(positional, config)?;
                 let deferred_named = named
                     .iter()
-                    .map(|(identifier, value)| Ok(((*identifier).clone(), convert_value(value)?)))
+                    .map(|(identifier, value)| Ok(((*identifier).clone(), convert_value(value, config)?)))
                     .collect::<Result<Vec<_>, _>>()?;
 
                 Ok(DeferredValues::new(deferred_positional, deferred_named))
             }
 
-            fn convert_values<'v>(decoded_values: &'v [Value]) -> Result<Vec<DeferredValue<'v>>, DeferredFormatError> {
-                decoded_values.iter().map(convert_value).collect()
+            fn convert_values<'v>(
+                decoded_values: &'v [Value],
+                config: &DeferredFormatConfig,
+            ) -> Result<Vec<DeferredValue<'v>>, DeferredFormatError> {
+                decoded_values
+                    .iter()
+                    .map(|value| convert_value(value, config))
+                    .collect()
             }
 
-            fn convert_value<'v>(decoded_value: &'v Value) -> Result<DeferredValue<'v>, DeferredFormatError> {
+            fn convert_value<'v>(
+                decoded_value: &'v Value,
+                config: &DeferredFormatConfig,
+            ) -> Result<DeferredValue<'v>, DeferredFormatError> {
                 let value = match decoded_value {
                     Value::Boolean(value) => DeferredValue::Boolean(*value),
                     Value::Usize(value) => DeferredValue::U64(*value),
@@ -181,33 +256,48 @@ mod printer {
                     Value::F64(value) => DeferredValue::F64(*value),
                     Value::Char(value) => DeferredValue::Char(*value),
                     Value::String(value) => DeferredValue::String(value.into()),
-                    Value::List(values) => convert_values(values).map(DeferredValue::List)?,
-                    Value::Tuple(values) => convert_values(values).map(DeferredValue::Tuple)?,
+                    Value::Duration(value) => DeferredValue::Duration(*value),
+                    Value::IpAddr(value) => DeferredValue::IpAddr(*value),
+                    Value::SocketAddr(value) => DeferredValue::SocketAddr(*value),
+                    Value::Pointer { address, pointer_width } => DeferredValue::Pointer(DeferredPointerValue {
+                        address: *address,
+                        bits: pointer_width.size() as u32 * 8,
+                    }),
+                    Value::Bytes(bytes) => DeferredValue::Bytes(bytes.as_slice().into()),
+                    Value::List(values) => convert_values(values, config).map(DeferredValue::List)?,
+                    Value::Tuple(values) => convert_values(values, config).map(DeferredValue::Tuple)?,
                     Value::Type(value) => {
-                        let TypeStructureValue { name, variant } = value;
+                        let TypeStructureValue { name, module_path, generic_args, variant, display } = value;
 
                         let deferred_variant = match variant {
                             TypeStructureVariantValue::Struct(decoded_struct_variant) => {
-                                convert_struct_variant(decoded_struct_variant).map(DeferredTypeVariant::Struct)?
+                                convert_struct_variant(decoded_struct_variant, config)
+                                    .map(DeferredTypeVariant::Struct)?
                             }
                             TypeStructureVariantValue::Enum((variant_name, variant_value)) => {
-                                let deferred_struct_value = convert_struct_variant(variant_value)?;
+                                let deferred_struct_value = convert_struct_variant(variant_value, config)?;
                                 DeferredTypeVariant::Enum((variant_name, deferred_struct_value))
                             }
                         };
 
-                        DeferredValue::Type(DeferredTypeValue { name, variant: deferred_variant })
+                        DeferredValue::Type(DeferredTypeValue {
+                            name,
+                            module_path,
+                            generic_args,
+                            variant: deferred_variant,
+                            display: *display,
+                        })
                     }
                     Value::WriteStatements(write_statements) => {
                         let mut write_statements_string = String::new();
 
                         for WriteStatementValue { expression, append_newline, decoded_values } in write_statements {
                             let pretty_string =
-                                PrettyPrinter::evaluate_statement(expression, decoded_values, *append_newline)?;
+                                PrettyPrinter::evaluate_statement(expression, decoded_values, *append_newline, config)?;
                             write_statements_string.push_str(&pretty_string);
                         }
 
-                        DeferredValue::String(write_statements_string.into())
+                        DeferredValue::Preformatted(write_statements_string.into())
                     }
                 };
 
@@ -216,13 +306,16 @@ mod printer {
 
             fn convert_struct_variant<'v>(
                 decoded_struct_variant: &'v StructVariantValue,
+                config: &DeferredFormatConfig,
             ) -> Result<DeferredStructVariant<'v>, DeferredFormatError> {
                 let deferred_variant = match decoded_struct_variant {
                     StructVariantValue::Unit => DeferredStructVariant::Unit,
-                    StructVariantValue::Tuple(values) => convert_values(values).map(DeferredStructVariant::Tuple)?,
+                    StructVariantValue::Tuple(values) => {
+                        convert_values(values, config).map(DeferredStructVariant::Tuple)?
+                    }
                     StructVariantValue::Named(fields) => fields
                         .iter()
-                        .map(|(field_name, field_value)| Ok((*field_name, convert_value(field_value)?)))
+                        .map(|(field_name, field_value)| Ok((*field_name, convert_value(field_value, config)?)))
                         .collect::<Result<_, _>>()
                         .map(DeferredStructVariant::Named)?,
                 };
@@ -231,6 +324,31 @@ mod printer {
             }
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn lost_frames() {
+            let mut printer = PrettyPrinter::new(PrettyPrinterConfig::new(PrintStampConfig::Counter));
+
+            assert_eq!(None, printer.evaluate_lost_frames(u16::MAX - 3));
+            assert_eq!(Some(2), printer.evaluate_lost_frames(u16::MAX));
+
+            // wrap
+            assert_eq!(None, printer.evaluate_lost_frames(0));
+            assert_eq!(Some(2), printer.evaluate_lost_frames(3));
+
+            // restart, ex. after a reboot
+            assert_eq!(None, printer.evaluate_lost_frames(0));
+            assert_eq!(None, printer.evaluate_lost_frames(1));
+
+            // frames lost across a wrap
+            printer.last_sequence = Some(u16::MAX - 1);
+            assert_eq!(Some(2), printer.evaluate_lost_frames(1));
+        }
+    }
 }
 
 pub(crate) use config::*;
@@ -241,7 +359,7 @@ pub mod config {
         processor::{DynamicProcessorConfig, FormatProcessor, FormatProcessorError, ProcessedFormatString},
     };
 
-    const EXPECTED_NAMED_FORMAT_ARGUMENTS: [AnyIdentifier<'static>; 6] =
+    const EXPECTED_NAMED_FORMAT_ARGUMENTS: [AnyIdentifier<'static>; 9] =
         // SAFETY: all strings are valid identifiers
         unsafe {
             [
@@ -251,6 +369,9 @@ pub mod config {
                 AnyIdentifier::new_unchecked(false, "file"),
                 AnyIdentifier::new_unchecked(false, "line"),
                 AnyIdentifier::new_unchecked(false, "statement"),
+                AnyIdentifier::new_unchecked(false, "module"),
+                AnyIdentifier::new_unchecked(false, "target"),
+                AnyIdentifier::new_unchecked(false, "column"),
             ]
         };
 
@@ -272,6 +393,9 @@ pub mod config {
     pub struct PrettyPrinterConfig {
         pub(crate) stamp: PrintStampConfig,
         pub(crate) log_format_string: ProcessedFormatString<'static>,
+        pub(crate) full_type_names: bool,
+        pub(crate) list_separator: Option<String>,
+        pub(crate) bytes_limit: Option<usize>,
     }
 
     impl PrettyPrinterConfig {
@@ -287,8 +411,10 @@ pub mod config {
         /// Construct a new pretty printer configuration with a custom log statement format
         ///
         /// Format string can use the following  named parameters: "stamp",
-        /// "level", "crate", "file", "line", "statement". Order can be relied
-        /// on if the format string uses positional arguments.
+        /// "level", "crate", "file", "line", "statement", "module", "target",
+        /// "column".
+        /// Order can be relied on if the format string uses positional
+        /// arguments. "target" defaults to "module" for statements without one.
         pub fn new_with_format(
             stamp_config: PrintStampConfig,
             log_format_str: &str,
@@ -304,7 +430,40 @@ pub mod config {
                 &FORMAT_PROCESSOR_CONFIG,
             )?;
 
-            Ok(Self { stamp: stamp_config, log_format_string })
+            Ok(Self {
+                stamp: stamp_config,
+                log_format_string,
+                full_type_names: false,
+                list_separator: None,
+                bytes_limit: None,
+            })
+        }
+
+        /// Print derived types with their module path and generic arguments, like
+        /// `core::any::type_name`
+        ///
+        /// Ex. `my_crate::Wrapper<u8>(1)` rather than `Wrapper(1)`.
+        pub fn with_full_type_names(mut self) -> Self {
+            self.full_type_names = true;
+            self
+        }
+
+        /// Allow lists and tuples to be formatted with the non-debug format traits
+        ///
+        /// Elements are formatted with the same options and joined by the
+        /// given separator, ex. `{:02x}` on a byte slice with `" "` prints
+        /// `de ad be ef`.
+        pub fn with_list_separator(mut self, separator: impl Into<String>) -> Self {
+            self.list_separator = Some(separator.into());
+            self
+        }
+
+        /// Truncate hexdumps of `redefmt::Bytes` values after the given number of bytes
+        ///
+        /// Statements may still override it with a format precision, ex. `{:.64}`.
+        pub fn with_bytes_limit(mut self, bytes_limit: usize) -> Self {
+            self.bytes_limit = Some(bytes_limit);
+            self
         }
     }
 
@@ -1,8 +1,14 @@
 #![allow(missing_docs)]
 
 use redefmt_args::{
-    deferred::{DeferredFormatConfig, DeferredStructVariant, DeferredTypeValue, DeferredTypeVariant, DeferredValue},
+    deferred::{
+        DeferredFormatConfig, DeferredFormatError, DeferredPointerValue, DeferredStructVariant, DeferredTypeValue,
+        DeferredTypeVariant, DeferredValue,
+    },
     deferred_format,
+    format_string::FormatString,
+    identifier::AnyIdentifier,
+    processor::{DynamicProcessorConfig, FormatProcessor},
 };
 
 #[test]
@@ -12,9 +18,12 @@ fn ui() {
     t.pass("tests/ui/pass/**/*.rs");
 }
 
-const FORMAT_DEFERRED_CONFIG: DeferredFormatConfig = DeferredFormatConfig {
+const FORMAT_DEFERRED_CONFIG: DeferredFormatConfig<'static> = DeferredFormatConfig {
     allow_non_usize_precision_value: true,
     allow_non_usize_width_value: true,
+    full_type_names: false,
+    list_separator: None,
+    bytes_limit: None,
 };
 
 macro_rules! assert_evaluate {
@@ -25,6 +34,13 @@ macro_rules! assert_evaluate {
     };
 }
 
+macro_rules! assert_std_conformance {
+    ($format_string:literal, $value:expr) => {
+        let value = $value;
+        assert_evaluate!(format!($format_string, value), $format_string, value);
+    };
+}
+
 #[test]
 fn empty() {
     assert_evaluate!("", "");
@@ -202,12 +218,21 @@ fn tuple_debug() {
 
 #[test]
 fn tuple_debug_pretty() {
-    assert_evaluate!("(\n\t3,\n\t4,\n)", "{:#?}", (3, 4));
+    assert_evaluate!("(\n    3,\n    4,\n)", "{:#?}", (3, 4));
+    assert_std_conformance!("{:#?}", (3, 4));
+}
+
+#[test]
+fn tuple_single_element() {
+    // 1-tuples don't implement `AsDeferredValue`
+    let x = DeferredValue::Tuple(vec![DeferredValue::I32(1)]);
+    assert_evaluate!(format!("{:?}", (1,)), "{:?}", x);
+    assert_evaluate!(format!("{:#?}", (1,)), "{:#?}", x);
 }
 
 #[test]
 fn tuple_passes_format_options() {
-    assert_evaluate!("(\n\t0x1--,\n\t0x1--,\n)", "{:-<#5x?}", (1, 1));
+    assert_evaluate!("(\n    0x1--,\n    0x1--,\n)", "{:-<#5x?}", (1, 1));
 }
 
 #[test]
@@ -218,8 +243,9 @@ fn tuple_nested() {
 
 #[test]
 fn tuple_nested_pretty() {
-    let str = "(\n\t(\n\t\t1,\n\t\t2,\n\t),\n\t(\n\t\t3,\n\t\t(\n\t\t\t4,\n\t\t\t5,\n\t\t),\n\t),\n\t6,\n)";
+    let str = "(\n    (\n        1,\n        2,\n    ),\n    (\n        3,\n        (\n            4,\n            5,\n        ),\n    ),\n    6,\n)";
     assert_evaluate!(str, "{:#?}", ((1, 2), (3, (4, 5)), 6));
+    assert_std_conformance!("{:#?}", ((1, 2), (3, (4, 5)), 6));
 }
 
 #[test]
@@ -238,8 +264,18 @@ fn list_nested() {
 
 #[test]
 fn list_nested_pretty() {
-    let str = "[\n\t[\n\t\t1,\n\t\t2,\n\t],\n\t[\n\t\t3,\n\t\t4,\n\t],\n\t[\n\t\t5,\n\t\t6,\n\t],\n]";
+    let str = "[\n    [\n        1,\n        2,\n    ],\n    [\n        3,\n        4,\n    ],\n    [\n        5,\n        6,\n    ],\n]";
     assert_evaluate!(str, "{:#?}", [[1, 2], [3, 4], [5, 6]]);
+    assert_std_conformance!("{:#?}", [[1, 2], [3, 4], [5, 6]]);
+    assert_std_conformance!("{:#x?}", vec![(10, "a"), (11, "b\nc")]);
+}
+
+#[test]
+fn preformatted() {
+    let x = DeferredValue::Preformatted("x\ny".into());
+    let list = [x.clone()];
+    assert_evaluate!("x\ny", "{:>5?}", x);
+    assert_evaluate!("[\n    x\n    y,\n]", "{:#?}", list);
 }
 
 #[test]
@@ -257,7 +293,10 @@ fn unit_struct_pretty() {
 fn mock_unit_struct() -> DeferredValue<'static> {
     DeferredValue::Type(DeferredTypeValue {
         name: "Foo",
+        module_path: "",
+        generic_args: &[],
         variant: DeferredTypeVariant::Struct(DeferredStructVariant::Unit),
+        display: None,
     })
 }
 
@@ -270,16 +309,19 @@ fn tuple_struct() {
 #[test]
 fn tuple_struct_pretty() {
     let x = mock_tuple_struct();
-    assert_evaluate!("Bar(\n\ttrue,\n\t1,\n)", "{:#?}", x);
+    assert_evaluate!("Bar(\n    true,\n    1,\n)", "{:#?}", x);
 }
 
 fn mock_tuple_struct() -> DeferredValue<'static> {
     DeferredValue::Type(DeferredTypeValue {
         name: "Bar",
+        module_path: "",
+        generic_args: &[],
         variant: DeferredTypeVariant::Struct(DeferredStructVariant::Tuple(vec![
             DeferredValue::Boolean(true),
             DeferredValue::Usize(1),
         ])),
+        display: None,
     })
 }
 
@@ -294,7 +336,10 @@ fn empty_tuple_struct() {
 fn mock_empty_tuple_struct() -> DeferredValue<'static> {
     DeferredValue::Type(DeferredTypeValue {
         name: "Bar",
+        module_path: "",
+        generic_args: &[],
         variant: DeferredTypeVariant::Struct(DeferredStructVariant::Tuple(vec![])),
+        display: None,
     })
 }
 
@@ -307,19 +352,45 @@ fn named_struct() {
 #[test]
 fn named_struct_pretty() {
     let x = mock_named_struct();
-    assert_evaluate!("Baz {\n\tx: true,\n\ty: 1,\n}", "{:#?}", x);
+    assert_evaluate!("Baz {\n    x: true,\n    y: 1,\n}", "{:#?}", x);
 }
 
 fn mock_named_struct() -> DeferredValue<'static> {
     DeferredValue::Type(DeferredTypeValue {
         name: "Baz",
+        module_path: "",
+        generic_args: &[],
         variant: DeferredTypeVariant::Struct(DeferredStructVariant::Named(vec![
             ("x", DeferredValue::Boolean(true)),
             ("y", DeferredValue::Usize(1)),
         ])),
+        display: None,
     })
 }
 
+#[test]
+fn named_struct_display() {
+    let display = FormatProcessor::process_dynamic(
+        FormatString::parse("{x}:{y:>3}").unwrap(),
+        0,
+        &[AnyIdentifier::parse("x").unwrap(), AnyIdentifier::parse("y").unwrap()],
+        &DynamicProcessorConfig {
+            disable_unused_named_check: false,
+            disable_unused_positional_check: false,
+        },
+    )
+    .unwrap();
+
+    let DeferredValue::Type(mut type_value) = mock_named_struct() else {
+        unreachable!()
+    };
+    type_value.display = Some(&display);
+    let x = DeferredValue::Type(type_value);
+
+    assert_evaluate!("true:  1", "{}", x);
+    assert_evaluate!("Baz { x: true, y: 1 }", "{:?}", x);
+}
+
 #[test]
 fn empty_named_struct() {
     let x = mock_empty_named_struct();
@@ -331,7 +402,10 @@ fn empty_named_struct() {
 fn mock_empty_named_struct() -> DeferredValue<'static> {
     DeferredValue::Type(DeferredTypeValue {
         name: "Baz",
+        module_path: "",
+        generic_args: &[],
         variant: DeferredTypeVariant::Struct(DeferredStructVariant::Named(vec![])),
+        display: None,
     })
 }
 
@@ -344,22 +418,28 @@ fn nested_struct() {
 #[test]
 fn nested_struct_pretty() {
     let x = mock_nested_struct();
-    assert_evaluate!("Baz {\n\tqux: Qux {\n\t\tvalue: true,\n\t},\n}", "{:#?}", x);
+    assert_evaluate!("Baz {\n    qux: Qux {\n        value: true,\n    },\n}", "{:#?}", x);
 }
 
 fn mock_nested_struct() -> DeferredValue<'static> {
     DeferredValue::Type(DeferredTypeValue {
         name: "Baz",
+        module_path: "",
+        generic_args: &[],
         variant: DeferredTypeVariant::Struct(DeferredStructVariant::Named(vec![(
             "qux",
             DeferredValue::Type(DeferredTypeValue {
                 name: "Qux",
+                module_path: "",
+                generic_args: &[],
                 variant: DeferredTypeVariant::Struct(DeferredStructVariant::Named(vec![(
                     "value",
                     DeferredValue::Boolean(true),
                 )])),
+                display: None,
             }),
         )])),
+        display: None,
     })
 }
 
@@ -371,12 +451,159 @@ fn enum_format() {
     assert_evaluate!("A(true, 1)", "{:?}", x);
 }
 
+#[test]
+fn full_type_names() {
+    let generic_args = ["u8".to_string(), "alloc::string::String".to_string()];
+
+    let DeferredValue::Type(mut type_value) = mock_tuple_struct() else {
+        unreachable!()
+    };
+    type_value.module_path = "x::y";
+    type_value.generic_args = &generic_args;
+    let tuple_struct = DeferredValue::Type(type_value);
+
+    let DeferredValue::Type(mut type_value) = mock_enum() else {
+        unreachable!()
+    };
+    type_value.module_path = "x";
+    let enum_value = DeferredValue::Type(type_value);
+
+    let config = DeferredFormatConfig { full_type_names: true, ..FORMAT_DEFERRED_CONFIG };
+
+    let (expression, values) = deferred_format!("{:?} {:?}", tuple_struct, enum_value);
+    let actual_string = expression.format_deferred(&values, &config).unwrap();
+
+    assert_eq!(
+        "x::y::Bar<u8, alloc::string::String>(true, 1) x::Foo::A(true, 1)",
+        actual_string
+    );
+}
+
 fn mock_enum() -> DeferredValue<'static> {
     DeferredValue::Type(DeferredTypeValue {
         name: "Foo",
+        module_path: "",
+        generic_args: &[],
         variant: DeferredTypeVariant::Enum((
             "A",
             DeferredStructVariant::Tuple(vec![DeferredValue::Boolean(true), DeferredValue::Usize(1)]),
         )),
+        display: None,
     })
 }
+
+#[test]
+fn duration_format() {
+    let duration = std::time::Duration::new(1, 500_000_000);
+
+    assert_std_conformance!("{:?}", duration);
+    assert_std_conformance!("{:+?}", duration);
+    assert_std_conformance!("{:.3?}", duration);
+    assert_std_conformance!("{:.0?}", duration);
+    assert_std_conformance!("{:10?}", duration);
+    // zero padding ignored
+    assert_std_conformance!("{:010?}", duration);
+    assert_std_conformance!("{:-^+10.2?}", duration);
+    assert_std_conformance!("{:?}", std::time::Duration::from_nanos(1500));
+    assert_std_conformance!("{:?}", std::time::Duration::ZERO);
+}
+
+#[test]
+fn ip_addr_format() {
+    let ipv4_addr = std::net::IpAddr::from([10, 0, 0, 1]);
+    let ipv6_addr = std::net::IpAddr::from([0xfe80, 0, 0, 0, 0, 0, 0, 1]);
+
+    assert_std_conformance!("{}", ipv4_addr);
+    assert_std_conformance!("{:?}", ipv4_addr);
+    assert_std_conformance!("{:>20}", ipv4_addr);
+    assert_std_conformance!("{:.4}", ipv4_addr);
+    assert_std_conformance!("{}", ipv6_addr);
+}
+
+#[test]
+fn socket_addr_format() {
+    let ipv4_socket_addr = std::net::SocketAddr::from(([10, 0, 0, 1], 80));
+    let ipv6_socket_addr =
+        std::net::SocketAddr::V6(std::net::SocketAddrV6::new(std::net::Ipv6Addr::LOCALHOST, 80, 0, 3));
+
+    assert_std_conformance!("{}", ipv4_socket_addr);
+    assert_std_conformance!("{:?}", ipv4_socket_addr);
+    assert_std_conformance!("{:20}", ipv4_socket_addr);
+    assert_std_conformance!("{}", ipv6_socket_addr);
+}
+
+#[test]
+fn pointer_format() {
+    let value = 1u8;
+    let pointer = &raw const value;
+
+    assert_std_conformance!("{:p}", pointer);
+    assert_std_conformance!("{:#p}", pointer);
+    assert_std_conformance!("{:?}", pointer);
+    assert_std_conformance!("{:#30p}", pointer);
+    assert_std_conformance!("{:>30p}", pointer);
+    assert_std_conformance!("{:030p}", pointer);
+    assert_std_conformance!("{:p}", std::ptr::null::<u8>());
+    assert_std_conformance!("{:#p}", std::ptr::null_mut::<u8>());
+}
+
+#[test]
+fn pointer_target_width() {
+    let pointer = DeferredValue::Pointer(DeferredPointerValue { address: 0x2000_0100, bits: 32 });
+
+    assert_evaluate!("0x20000100", "{:p}", pointer);
+    assert_evaluate!("0x20000100", "{:#p}", pointer);
+
+    let pointer = DeferredValue::Pointer(DeferredPointerValue { address: 0xff, bits: 16 });
+
+    assert_evaluate!("0xff", "{:p}", pointer);
+    assert_evaluate!("0x00ff", "{:#p}", pointer);
+}
+
+#[test]
+fn list_display() {
+    let config = DeferredFormatConfig { list_separator: Some(" "), ..FORMAT_DEFERRED_CONFIG };
+
+    let bytes = [0xde_u8, 0xad, 0x0b];
+    let pair = (1u8, 'x');
+
+    let (expression, values) = deferred_format!("{bytes:02x} | {bytes:#X} | {bytes:>3} | {pair}");
+    let actual_string = expression.format_deferred(&values, &config).unwrap();
+
+    assert_eq!("de ad 0b | 0xDE 0xAD 0xB | 222 173  11 | 1 x", actual_string);
+}
+
+#[test]
+fn list_display_not_enabled() {
+    let (expression, values) = deferred_format!("{:x}", [1u8, 2]);
+    let error = expression
+        .format_deferred(&values, &FORMAT_DEFERRED_CONFIG)
+        .unwrap_err();
+
+    assert!(matches!(error, DeferredFormatError::FormatNotImplemented(..)));
+}
+
+#[test]
+fn bytes_hexdump() {
+    let bytes = DeferredValue::Bytes(b"Hello, world!\n\x00\x01\xff redefmt".as_slice().into());
+
+    assert_evaluate!(
+        "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a 0001  Hello, world!...\n\
+         00000010: ff20 7265 6465 666d 74                   . redefmt",
+        "{}",
+        bytes
+    );
+    assert_evaluate!(
+        "00000000: 4865                                     He\n... 23 more bytes",
+        "{:.2X}",
+        bytes
+    );
+
+    let config = DeferredFormatConfig { bytes_limit: Some(0), ..FORMAT_DEFERRED_CONFIG };
+    let (expression, values) = deferred_format!("{:?}", bytes);
+    let actual_string = expression.format_deferred(&values, &config).unwrap();
+    assert_eq!("... 25 more bytes", actual_string);
+
+    let empty = DeferredValue::Bytes(b"".as_slice().into());
+    assert_evaluate!("", "{}", empty);
+}
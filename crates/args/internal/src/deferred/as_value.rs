@@ -1,4 +1,8 @@
 use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
 
 use crate::*;
 
@@ -80,6 +84,23 @@ impl_copy!(i128, I128);
 impl_copy!(f32, F32);
 impl_copy!(f64, F64);
 impl_copy!(char, Char);
+impl_copy!(Duration, Duration);
+impl_copy!(IpAddr, IpAddr);
+impl_copy!(SocketAddr, SocketAddr);
+
+macro_rules! impl_pointer {
+    ($t:ty) => {
+        impl<T: ?Sized> private::Sealed for $t {}
+        impl<T: ?Sized> AsDeferredValue for $t {
+            fn as_deferred_value(&self) -> DeferredValue<'_> {
+                DeferredValue::Pointer(DeferredPointerValue { address: self.addr() as u64, bits: usize::BITS })
+            }
+        }
+    };
+}
+
+impl_pointer!(*const T);
+impl_pointer!(*mut T);
 
 macro_rules! impl_list {
     () => {
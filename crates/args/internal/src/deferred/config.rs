@@ -1,6 +1,6 @@
 /// Passed to [`ProcessedFormatString::format_deferred`](crate::ProcessedFormatString::format_deferred)
 #[derive(Default, Clone, Copy)]
-pub struct DeferredFormatConfig {
+pub struct DeferredFormatConfig<'a> {
     /// Opt-out of `format_args!` "compatibility" by allowing format precision option arguments
     /// to be any signed or unsigned integer which can be successfully converted into a an `usize`.
     /// `usize` can normally be inferred for `format_args!`, but defaults to `i32` when
@@ -9,4 +9,16 @@ pub struct DeferredFormatConfig {
     pub allow_non_usize_precision_value: bool,
     /// Same as [`Self::allow_non_usize_precision_value`], but for format width options.
     pub allow_non_usize_width_value: bool,
+    /// Print types with their module path and generic arguments when debug formatted, as
+    /// given by `core::any::type_name`. Enum variants are then prefixed by their type name,
+    /// ex. `my_crate::Wrapper<u8>(1)` and `my_crate::Packet::Ack`.
+    pub full_type_names: bool,
+    /// Opt into formatting lists and tuples with the non-debug format traits, ex. `{}`, `{:x}`
+    /// or `{:02X}`. Each element is formatted with the same options, and separated by the given
+    /// separator. A `" "` separator would for example format `[0xde, 0xad]` with `{:02x}` into
+    /// `de ad`. `DeferredFormatError::FormatNotImplemented` is otherwise returned.
+    pub list_separator: Option<&'a str>,
+    /// Maximum number of bytes included in the hexdumps of `DeferredValue::Bytes`, the rest
+    /// being summarized by a trailing line. A format precision, ex. `{:.64}`, takes precedence.
+    pub bytes_limit: Option<usize>,
 }
@@ -3,7 +3,8 @@ pub use values::DeferredValues;
 
 mod value;
 pub use value::{
-    DeferredStructVariant, DeferredTypeValue, DeferredTypeVariant, DeferredValue, DeferredValueDiscriminant,
+    DeferredPointerValue, DeferredStructVariant, DeferredTypeValue, DeferredTypeVariant, DeferredValue,
+    DeferredValueDiscriminant,
 };
 
 mod as_value;
@@ -4,7 +4,11 @@ use alloc::{
     string::{String, ToString},
     vec::Vec,
 };
-use core::fmt::{Binary, Debug, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex};
+use core::{
+    fmt::{Binary, Debug, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex},
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
 
 use crate::*;
 
@@ -27,16 +31,73 @@ pub enum DeferredValue<'a> {
     F64(f64),
     Char(char),
     String(Cow<'a, str>),
+    Duration(Duration),
+    IpAddr(IpAddr),
+    SocketAddr(SocketAddr),
+    Pointer(DeferredPointerValue),
+    /// Binary blob, formatted as an `xxd` style hexdump
+    Bytes(Cow<'a, [u8]>),
+    /// Output of a manually written format implementation
+    ///
+    /// Pushed as is regardless of the format options, like `write!` calls in
+    /// a `core::fmt` trait implementation. Only indented when nested in
+    /// pretty printed values.
+    Preformatted(Cow<'a, str>),
     // Reused for array, vec and slice
     List(Vec<DeferredValue<'a>>),
     Tuple(Vec<DeferredValue<'a>>),
     Type(DeferredTypeValue<'a>),
 }
 
+/// Pointer address of the target, formatted like `core::fmt::Pointer`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeferredPointerValue {
+    pub address: u64,
+    /// Target pointer width, zero padding `{:#p}` to its hex digit count
+    pub bits: u32,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DeferredTypeValue<'a> {
     pub name: &'a str,
+    /// Module path of the type definition, may be empty
+    pub module_path: &'a str,
+    /// Type names of the generic type parameters, as given by `core::any::type_name`
+    pub generic_args: &'a [String],
     pub variant: DeferredTypeVariant<'a>,
+    /// Display format string, evaluated with the struct fields as arguments
+    ///
+    /// Tuple struct fields are passed as positional arguments, named struct
+    /// fields as named arguments.
+    pub display: Option<&'a ProcessedFormatString<'a>>,
+}
+
+impl DeferredTypeValue<'_> {
+    /// Fully qualified name, mirroring the output of `core::any::type_name`
+    pub fn type_name(&self) -> String {
+        let mut type_name = String::new();
+
+        if !self.module_path.is_empty() {
+            type_name.push_str(self.module_path);
+            type_name.push_str("::");
+        }
+
+        type_name.push_str(self.name);
+
+        if let [first_arg, remaining_args @ ..] = self.generic_args {
+            type_name.push('<');
+            type_name.push_str(first_arg);
+
+            for arg in remaining_args {
+                type_name.push_str(", ");
+                type_name.push_str(arg);
+            }
+
+            type_name.push('>');
+        }
+
+        type_name
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -70,6 +131,12 @@ pub enum DeferredValueDiscriminant {
     F64,
     Char,
     String,
+    Duration,
+    IpAddr,
+    SocketAddr,
+    Pointer,
+    Bytes,
+    Preformatted,
     List,
     Tuple,
     Type,
@@ -95,6 +162,12 @@ impl Display for DeferredValueDiscriminant {
             DeferredValueDiscriminant::F64 => "f64",
             DeferredValueDiscriminant::Char => "char",
             DeferredValueDiscriminant::String => "string",
+            DeferredValueDiscriminant::Duration => "duration",
+            DeferredValueDiscriminant::IpAddr => "IP address",
+            DeferredValueDiscriminant::SocketAddr => "socket address",
+            DeferredValueDiscriminant::Pointer => "pointer",
+            DeferredValueDiscriminant::Bytes => "bytes",
+            DeferredValueDiscriminant::Preformatted => "preformatted string",
             DeferredValueDiscriminant::List => "list",
             DeferredValueDiscriminant::Tuple => "tuple",
             DeferredValueDiscriminant::Type => "type",
@@ -108,11 +181,16 @@ impl Display for DeferredValueDiscriminant {
 enum ValueClass {
     Numeric,
     Structure,
+    // Padded like `Misc`, but precision sets the fractional digits instead of truncating.
+    Duration,
     Misc,
 }
 
-#[derive(Default)]
-struct EvaluationContext {
+// Same as `core::fmt` pretty printing
+const INDENTATION: &str = "    ";
+
+struct EvaluationContext<'c> {
+    config: &'c DeferredFormatConfig<'c>,
     indentation: usize,
 }
 
@@ -136,6 +214,12 @@ impl<'a> DeferredValue<'a> {
             DeferredValue::F64(_) => DeferredValueDiscriminant::F64,
             DeferredValue::Char(_) => DeferredValueDiscriminant::Char,
             DeferredValue::String(_) => DeferredValueDiscriminant::String,
+            DeferredValue::Duration(_) => DeferredValueDiscriminant::Duration,
+            DeferredValue::IpAddr(_) => DeferredValueDiscriminant::IpAddr,
+            DeferredValue::SocketAddr(_) => DeferredValueDiscriminant::SocketAddr,
+            DeferredValue::Pointer(_) => DeferredValueDiscriminant::Pointer,
+            DeferredValue::Bytes(_) => DeferredValueDiscriminant::Bytes,
+            DeferredValue::Preformatted(_) => DeferredValueDiscriminant::Preformatted,
             DeferredValue::List(_) => DeferredValueDiscriminant::List,
             DeferredValue::Tuple(_) => DeferredValueDiscriminant::Tuple,
             DeferredValue::Type(_) => DeferredValueDiscriminant::Type,
@@ -146,8 +230,9 @@ impl<'a> DeferredValue<'a> {
         &self,
         string_buffer: &mut String,
         options: &ResolvedFormatOptions,
+        config: &DeferredFormatConfig,
     ) -> Result<(), DeferredFormatError> {
-        let mut evaltation_context = EvaluationContext::default();
+        let mut evaltation_context = EvaluationContext { config, indentation: 0 };
         self.format_deferred_impl(string_buffer, &mut evaltation_context, options)
     }
 
@@ -202,6 +287,77 @@ impl<'a> DeferredValue<'a> {
                     ));
                 }
             },
+            DeferredValue::Duration(value) => match format_trait {
+                FormatTrait::Debug | FormatTrait::DebugLowerHex | FormatTrait::DebugUpperHex => {
+                    duration_string(value, options)
+                }
+                FormatTrait::Pointer => pointer_string(value, options),
+                _ => {
+                    return Err(DeferredFormatError::FormatNotImplemented(
+                        format_trait,
+                        self.discriminant(),
+                    ));
+                }
+            },
+            DeferredValue::IpAddr(value) => match format_trait {
+                FormatTrait::Display | FormatTrait::Debug | FormatTrait::DebugLowerHex | FormatTrait::DebugUpperHex => {
+                    value.to_string()
+                }
+                FormatTrait::Pointer => pointer_string(value, options),
+                _ => {
+                    return Err(DeferredFormatError::FormatNotImplemented(
+                        format_trait,
+                        self.discriminant(),
+                    ));
+                }
+            },
+            DeferredValue::SocketAddr(value) => match format_trait {
+                FormatTrait::Display | FormatTrait::Debug | FormatTrait::DebugLowerHex | FormatTrait::DebugUpperHex => {
+                    value.to_string()
+                }
+                FormatTrait::Pointer => pointer_string(value, options),
+                _ => {
+                    return Err(DeferredFormatError::FormatNotImplemented(
+                        format_trait,
+                        self.discriminant(),
+                    ));
+                }
+            },
+            DeferredValue::Pointer(pointer) => match format_trait {
+                FormatTrait::Pointer | FormatTrait::Debug | FormatTrait::DebugLowerHex | FormatTrait::DebugUpperHex => {
+                    pointer_address_string(pointer, options)
+                }
+                _ => {
+                    return Err(DeferredFormatError::FormatNotImplemented(
+                        format_trait,
+                        self.discriminant(),
+                    ));
+                }
+            },
+            DeferredValue::Bytes(bytes) => {
+                let upper = match format_trait {
+                    FormatTrait::Display | FormatTrait::Debug | FormatTrait::DebugLowerHex | FormatTrait::LowerHex => {
+                        false
+                    }
+                    FormatTrait::DebugUpperHex | FormatTrait::UpperHex => true,
+                    _ => {
+                        return Err(DeferredFormatError::FormatNotImplemented(
+                            format_trait,
+                            self.discriminant(),
+                        ));
+                    }
+                };
+
+                let limit = options.precision.or(evaluation_context.config.bytes_limit);
+                let hexdump = hexdump_string(bytes, limit, upper);
+                push_indented(string_buffer, &hexdump, evaluation_context);
+
+                return Ok(());
+            }
+            DeferredValue::Preformatted(value) => {
+                push_indented(string_buffer, value, evaluation_context);
+                return Ok(());
+            }
             DeferredValue::Usize(value) => integer_string(value, options),
             DeferredValue::U8(value) => integer_string(value, options),
             DeferredValue::U16(value) => integer_string(value, options),
@@ -222,28 +378,35 @@ impl<'a> DeferredValue<'a> {
                 }
                 FormatTrait::Pointer => pointer_string(values, options),
                 _ => {
-                    return Err(DeferredFormatError::FormatNotImplemented(
-                        format_trait,
-                        self.discriminant(),
-                    ));
+                    return separated_string(string_buffer, values, evaluation_context, options, self.discriminant());
                 }
             },
             DeferredValue::Tuple(values) => match format_trait {
                 FormatTrait::Debug | FormatTrait::DebugLowerHex | FormatTrait::DebugUpperHex => {
+                    // `(1,)` rather than `(1)`, parenthesized values aren't tuples
+                    if let [value] = values.as_slice()
+                        && !options.use_alternate_form
+                    {
+                        string_buffer.push('(');
+                        value.format_deferred_impl(string_buffer, evaluation_context, options)?;
+                        string_buffer.push_str(",)");
+                        return Ok(());
+                    }
+
                     return collection_string(string_buffer, values, evaluation_context, options, '(', ')', false);
                 }
                 FormatTrait::Pointer => pointer_string(values, options),
                 _ => {
-                    return Err(DeferredFormatError::FormatNotImplemented(
-                        format_trait,
-                        self.discriminant(),
-                    ));
+                    return separated_string(string_buffer, values, evaluation_context, options, self.discriminant());
                 }
             },
             DeferredValue::Type(type_value) => match format_trait {
                 FormatTrait::Debug | FormatTrait::DebugLowerHex | FormatTrait::DebugUpperHex => {
                     return type_string(string_buffer, type_value, evaluation_context, options);
                 }
+                FormatTrait::Display if type_value.display.is_some() => {
+                    return type_display_string(string_buffer, type_value, evaluation_context);
+                }
                 FormatTrait::Pointer => pointer_string(type_value, options),
                 _ => {
                     return Err(DeferredFormatError::FormatNotImplemented(
@@ -263,7 +426,14 @@ impl<'a> DeferredValue<'a> {
 
     fn value_class(&self) -> ValueClass {
         match self {
-            DeferredValue::Boolean(_) | DeferredValue::Char(_) | DeferredValue::String(_) => ValueClass::Misc,
+            DeferredValue::Boolean(_)
+            | DeferredValue::Char(_)
+            | DeferredValue::String(_)
+            | DeferredValue::IpAddr(_)
+            | DeferredValue::SocketAddr(_)
+            | DeferredValue::Bytes(_)
+            | DeferredValue::Preformatted(_) => ValueClass::Misc,
+            DeferredValue::Duration(_) => ValueClass::Duration,
             DeferredValue::List(_) | DeferredValue::Tuple(_) | DeferredValue::Type(_) => ValueClass::Structure,
             DeferredValue::Usize(_)
             | DeferredValue::U8(_)
@@ -277,6 +447,7 @@ impl<'a> DeferredValue<'a> {
             | DeferredValue::I32(_)
             | DeferredValue::I64(_)
             | DeferredValue::I128(_)
+            | DeferredValue::Pointer(_)
             | DeferredValue::F32(_)
             | DeferredValue::F64(_) => ValueClass::Numeric,
         }
@@ -372,6 +543,17 @@ fn integer_string<T: Display + Binary + LowerHex + UpperHex + Octal + LowerExp +
     }
 }
 
+fn duration_string(duration: &Duration, options: &ResolvedFormatOptions) -> String {
+    let ResolvedFormatOptions { sign, precision, .. } = options;
+
+    match (sign, precision) {
+        (false, None) => format!("{duration:?}"),
+        (true, None) => format!("{duration:+?}"),
+        (false, Some(precision)) => format!("{duration:.precision$?}"),
+        (true, Some(precision)) => format!("{duration:+.precision$?}"),
+    }
+}
+
 fn pointer_string<T>(t: T, options: &ResolvedFormatOptions) -> String {
     let ResolvedFormatOptions { sign, use_alternate_form, use_zero_padding, width, .. } = options;
 
@@ -389,6 +571,88 @@ fn pointer_string<T>(t: T, options: &ResolvedFormatOptions) -> String {
     }
 }
 
+// Same as `core::fmt::pointer_fmt_inner`, but with the target pointer width
+// rather than that of the host.
+fn pointer_address_string(pointer: &DeferredPointerValue, options: &ResolvedFormatOptions) -> String {
+    let DeferredPointerValue { address, bits } = pointer;
+
+    let mut hex_options = ResolvedFormatOptions {
+        align: options.align,
+        sign: options.sign,
+        use_alternate_form: true,
+        use_zero_padding: options.use_zero_padding,
+        width: options.width,
+        precision: options.precision,
+        format_trait: FormatTrait::LowerHex,
+    };
+
+    if options.use_alternate_form {
+        hex_options.use_zero_padding = true;
+
+        if hex_options.width == 0 {
+            hex_options.width = *bits as usize / 4 + 2;
+        }
+    }
+
+    integer_string(address, &hex_options)
+}
+
+const HEXDUMP_LINE_LENGTH: usize = 16;
+
+// Same layout as `xxd`, bytes grouped in pairs, ex:
+// `00000000: 6865 6c6c 6f0a                           hello.`
+fn hexdump_string(bytes: &[u8], limit: Option<usize>, upper: bool) -> String {
+    let dumped_length = limit.map_or(bytes.len(), |limit| limit.min(bytes.len()));
+
+    let mut hexdump = String::new();
+
+    for (line_index, line) in bytes[..dumped_length].chunks(HEXDUMP_LINE_LENGTH).enumerate() {
+        if line_index != 0 {
+            hexdump.push('\n');
+        }
+
+        let offset = line_index * HEXDUMP_LINE_LENGTH;
+
+        match upper {
+            true => hexdump.push_str(&format!("{offset:08X}:")),
+            false => hexdump.push_str(&format!("{offset:08x}:")),
+        }
+
+        for index in 0..HEXDUMP_LINE_LENGTH {
+            if index % 2 == 0 {
+                hexdump.push(' ');
+            }
+
+            match (line.get(index), upper) {
+                (Some(byte), true) => hexdump.push_str(&format!("{byte:02X}")),
+                (Some(byte), false) => hexdump.push_str(&format!("{byte:02x}")),
+                (None, _) => hexdump.push_str("  "),
+            }
+        }
+
+        hexdump.push_str("  ");
+
+        for byte in line {
+            match byte.is_ascii_graphic() || *byte == b' ' {
+                true => hexdump.push(*byte as char),
+                false => hexdump.push('.'),
+            }
+        }
+    }
+
+    let truncated_length = bytes.len() - dumped_length;
+
+    if truncated_length != 0 {
+        if dumped_length != 0 {
+            hexdump.push('\n');
+        }
+
+        hexdump.push_str(&format!("... {truncated_length} more bytes"));
+    }
+
+    hexdump
+}
+
 fn exp_string<T: UpperExp + LowerExp>(t: T, upper: bool, options: &ResolvedFormatOptions) -> String {
     let ResolvedFormatOptions { sign, use_zero_padding, width, precision, .. } = options;
 
@@ -470,7 +734,7 @@ fn collection_string_impl<T>(
 
     for (index, element) in elements.iter().enumerate() {
         if pretty {
-            (0..evaluation_context.indentation).for_each(|_| string_buffer.push('\t'));
+            push_indentation(string_buffer, evaluation_context);
         }
 
         print_fn(string_buffer, element, evaluation_context, options)?;
@@ -485,7 +749,7 @@ fn collection_string_impl<T>(
 
     if pretty {
         evaluation_context.indentation -= 1;
-        (0..evaluation_context.indentation).for_each(|_| string_buffer.push('\t'));
+        push_indentation(string_buffer, evaluation_context);
     } else if space_padding {
         string_buffer.push(' ');
     }
@@ -495,25 +759,122 @@ fn collection_string_impl<T>(
     Ok(())
 }
 
+// Opted into with `DeferredFormatConfig::list_separator`, formats each
+// element with the same options, ex. hex dumps with `{:02x}`.
+fn separated_string(
+    string_buffer: &mut String,
+    elements: &[DeferredValue],
+    evaluation_context: &mut EvaluationContext,
+    options: &ResolvedFormatOptions,
+    discriminant: DeferredValueDiscriminant,
+) -> Result<(), DeferredFormatError> {
+    let Some(separator) = evaluation_context.config.list_separator else {
+        return Err(DeferredFormatError::FormatNotImplemented(
+            options.format_trait,
+            discriminant,
+        ));
+    };
+
+    for (index, element) in elements.iter().enumerate() {
+        if index != 0 {
+            string_buffer.push_str(separator);
+        }
+
+        element.format_deferred_impl(string_buffer, evaluation_context, options)?;
+    }
+
+    Ok(())
+}
+
+fn push_indentation(string_buffer: &mut String, evaluation_context: &EvaluationContext) {
+    (0..evaluation_context.indentation).for_each(|_| string_buffer.push_str(INDENTATION));
+}
+
+// Mirrors the `PadAdapter` used by `core::fmt` for pretty printing, which
+// indents every line written by nested values.
+fn push_indented(string_buffer: &mut String, str: &str, evaluation_context: &EvaluationContext) {
+    for (index, line) in str.split('\n').enumerate() {
+        if index != 0 {
+            string_buffer.push('\n');
+            push_indentation(string_buffer, evaluation_context);
+        }
+
+        string_buffer.push_str(line);
+    }
+}
+
 fn type_string(
     string_buffer: &mut String,
     type_value: &DeferredTypeValue,
     evaluation_context: &mut EvaluationContext,
     options: &ResolvedFormatOptions,
 ) -> Result<(), DeferredFormatError> {
-    let DeferredTypeValue { name, variant } = type_value;
+    let DeferredTypeValue { name, variant, .. } = type_value;
+
+    let full_type_names = evaluation_context.config.full_type_names;
 
     match variant {
         DeferredTypeVariant::Struct(struct_variant) => {
-            struct_string(string_buffer, name, struct_variant, evaluation_context, options)
+            let name = match full_type_names {
+                true => Cow::Owned(type_value.type_name()),
+                false => Cow::Borrowed(*name),
+            };
+
+            struct_string(string_buffer, &name, struct_variant, evaluation_context, options)
         }
         DeferredTypeVariant::Enum((variant_name, struct_variant)) => {
-            // IMPROVEMENT: add configuration option to print enum_name?
-            struct_string(string_buffer, variant_name, struct_variant, evaluation_context, options)
+            let variant_name = match full_type_names {
+                true => Cow::Owned(format!("{}::{variant_name}", type_value.type_name())),
+                false => Cow::Borrowed(*variant_name),
+            };
+
+            struct_string(
+                string_buffer,
+                &variant_name,
+                struct_variant,
+                evaluation_context,
+                options,
+            )
         }
     }
 }
 
+// Display implementations written with `write!` ignore width and precision,
+// the evaluated string is therefore pushed as is.
+fn type_display_string(
+    string_buffer: &mut String,
+    type_value: &DeferredTypeValue,
+    evaluation_context: &EvaluationContext,
+) -> Result<(), DeferredFormatError> {
+    let DeferredTypeValue { variant, display, .. } = type_value;
+
+    let Some(display) = display else {
+        return Ok(());
+    };
+
+    let struct_variant = match variant {
+        DeferredTypeVariant::Struct(struct_variant) | DeferredTypeVariant::Enum((_, struct_variant)) => struct_variant,
+    };
+
+    let display_values = match struct_variant {
+        DeferredStructVariant::Unit => DeferredValues::new([], []),
+        DeferredStructVariant::Tuple(values) => DeferredValues::new(values.iter().cloned(), []),
+        DeferredStructVariant::Named(fields) => DeferredValues::new(
+            [],
+            fields.iter().filter_map(|(field_name, field_value)| {
+                AnyIdentifier::parse(*field_name)
+                    .ok()
+                    .map(|identifier| (identifier, field_value.clone()))
+            }),
+        ),
+    };
+
+    let display_string = display.format_deferred(&display_values, evaluation_context.config)?;
+    string_buffer.push_str(&display_string);
+
+    Ok(())
+}
+
 fn struct_string(
     string_buffer: &mut String,
     name: &str,
@@ -578,7 +939,7 @@ fn pipeline_length(value_class: ValueClass, mut value_string: String, options: &
     let apply_width = match value_class {
         ValueClass::Numeric => !use_zero_padding,
         ValueClass::Structure => false,
-        ValueClass::Misc => true,
+        ValueClass::Duration | ValueClass::Misc => true,
     };
 
     match apply_width && chars_count < *width {
@@ -114,13 +114,11 @@ impl<'a> FormatString<'a> {
                         }
                     }
                 }
-                CLOSING_BRACE => {
-                    if char_iter.next().is_none_or(|(_, next_char)| next_char != CLOSING_BRACE) {
-                        return Err(FormatStringParseError::new_char(
-                            offset + char_index,
-                            FormatStringSegmentError::UnmatchedClose,
-                        ));
-                    }
+                CLOSING_BRACE if char_iter.next().is_none_or(|(_, next_char)| next_char != CLOSING_BRACE) => {
+                    return Err(FormatStringParseError::new_char(
+                        offset + char_index,
+                        FormatStringSegmentError::UnmatchedClose,
+                    ));
                 }
                 _ => {}
             }
@@ -279,12 +279,10 @@ fn parse_count<'a>(
     // count integer or count index argument
     if let Some(first_digit) = first_char.to_digit(10) {
         let mut number = first_digit;
-        let mut power = 0u32;
 
         while let Some((_, next_char)) = str_iter.peek() {
             if let Some(digit) = next_char.to_digit(10) {
-                number += digit * 10u32.pow(power);
-                power += 1;
+                number = number * 10 + digit;
 
                 str_iter.next();
                 continue;
@@ -411,6 +409,17 @@ mod tests {
         assert_format_options("1", expected);
     }
 
+    #[test]
+    fn parse_width_count_multi_digit_literal() {
+        let expected = FormatOptions {
+            width: Some(FormatCount::Integer(12)),
+            format_trait: FormatTrait::Debug,
+            ..Default::default()
+        };
+
+        assert_format_options("12?", expected);
+    }
+
     #[test]
     fn parse_width_count_index_argument() {
         let expected = FormatOptions {
@@ -65,6 +65,12 @@ impl<'a> AnyIdentifier<'a> {
     }
 }
 
+impl<'a> From<AnyIdentifier<'a>> for FormatArgument<'a> {
+    fn from(identifier: AnyIdentifier<'a>) -> Self {
+        FormatArgument::Identifier(identifier.unraw())
+    }
+}
+
 impl Display for AnyIdentifier<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.raw {
@@ -1,4 +1,4 @@
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
 use crate::*;
 
@@ -32,7 +32,7 @@ impl<'a> ProcessedFormatString<'a> {
 
                     deferred_values
                         .get(argument)?
-                        .format_deferred(&mut string_buffer, &options)?;
+                        .format_deferred(&mut string_buffer, &options, config)?;
                 }
             }
         }
@@ -40,6 +40,44 @@ impl<'a> ProcessedFormatString<'a> {
         Ok(string_buffer)
     }
 
+    /// Format traits the given argument is formatted with, in order of appearance
+    ///
+    /// Usage as a width or precision count is included as `None`.
+    pub fn argument_usages(&self, argument: &FormatArgument) -> Vec<Option<FormatTrait>> {
+        let mut usages = Vec::new();
+
+        for segment in self.0.segments() {
+            let FormatStringSegment::Format(FormatArgumentSegment { argument: segment_argument, options }) = segment
+            else {
+                continue;
+            };
+
+            let count_argument = |count: Option<&FormatCount>| match count {
+                Some(FormatCount::Argument(count_argument)) => count_argument == argument,
+                _ => false,
+            };
+
+            let precision_count = match &options.precision {
+                Some(FormatPrecision::Count(count)) => Some(count),
+                _ => None,
+            };
+
+            if count_argument(options.width.as_ref()) {
+                usages.push(None);
+            }
+
+            if count_argument(precision_count) {
+                usages.push(None);
+            }
+
+            if segment_argument.as_ref() == Some(argument) {
+                usages.push(Some(options.format_trait));
+            }
+        }
+
+        usages
+    }
+
     #[doc(hidden)]
     pub unsafe fn new_unchecked(format_string: FormatString<'a>) -> Self {
         Self(format_string)
@@ -65,3 +103,29 @@ impl quote::ToTokens for ProcessedFormatString<'_> {
         tokens.extend(format_expression_tokens);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    #[test]
+    fn argument_usages() {
+        let format_expression: FormatExpression = parse_quote!("{0:p} {x:1$?} {0:.x$}", 1, 2, r#x = 3);
+        let processed_format_string = format_expression.processed_format_string;
+
+        let first_usages = processed_format_string.argument_usages(&FormatArgument::Index(0));
+        assert_eq!(
+            alloc::vec![Some(FormatTrait::Pointer), Some(FormatTrait::Display)],
+            first_usages
+        );
+
+        let second_usages = processed_format_string.argument_usages(&FormatArgument::Index(1));
+        assert_eq!(alloc::vec![None], second_usages);
+
+        let identifier = AnyIdentifier::parse("r#x").unwrap();
+        let named_usages = processed_format_string.argument_usages(&identifier.into());
+        assert_eq!(alloc::vec![Some(FormatTrait::Debug), None], named_usages);
+    }
+}
@@ -17,10 +17,6 @@
 //! build script is therefore a slightly premature optimization given that it is
 //! generated code itself which takes the longest to compile.
 
-// TEMP: should be part of next stable release (1.88)
-// https://github.com/rust-lang/rust/pull/140514
-#![feature(proc_macro_span)]
-
 use std::fmt::Display;
 
 use proc_macro::{Span, TokenStream, TokenTree};